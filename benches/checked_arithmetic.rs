@@ -0,0 +1,63 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ux::{i12, i40, u12, u127, u40, UxInteger};
+
+mod common;
+
+const LEN: usize = 1024;
+
+// Pseudo random operands covering the whole range of the type, so that a share of the
+// operations overflow.
+fn operands<T: UxInteger>(new: impl Fn(i128) -> T) -> Vec<(T, T)> {
+    let mut random = common::xorshift().map(i128::from);
+    let mut next = move || random.next().unwrap() << 64 | random.next().unwrap();
+    (0..LEN).map(|_| (new(next()), new(next()))).collect()
+}
+
+// Benchmarks the overflowing operations of the backing type against staging the operands
+// in an `i128` and range checking the result, which is what the wide reference does.
+macro_rules! bench_checked {
+    ($c:ident, $($name:ident),*) => {$(
+        let pairs = operands(|x| $name::saturating_from_i128(x % ($name::MAX.as_i128() + 1)));
+        let mut group = $c.benchmark_group(stringify!($name));
+        group.bench_function("checked_add/overflowing", |b| {
+            b.iter(|| {
+                for &(x, y) in black_box(&pairs) {
+                    black_box(x.checked_add(y));
+                }
+            })
+        });
+        group.bench_function("checked_add/wide", |b| {
+            b.iter(|| {
+                for &(x, y) in black_box(&pairs) {
+                    black_box($name::new_from(x.as_i128() + y.as_i128()).ok());
+                }
+            })
+        });
+        group.bench_function("checked_mul/overflowing", |b| {
+            b.iter(|| {
+                for &(x, y) in black_box(&pairs) {
+                    black_box(x.checked_mul(y));
+                }
+            })
+        });
+        group.bench_function("checked_mul/wide", |b| {
+            b.iter(|| {
+                for &(x, y) in black_box(&pairs) {
+                    black_box(
+                        x.as_i128()
+                            .checked_mul(y.as_i128())
+                            .and_then(|product| $name::new_from(product).ok()),
+                    );
+                }
+            })
+        });
+        group.finish();
+    )*};
+}
+
+fn checked_arithmetic(c: &mut Criterion) {
+    bench_checked!(c, u12, i12, u40, i40, u127);
+}
+
+criterion_group!(benches, checked_arithmetic);
+criterion_main!(benches);
@@ -0,0 +1,11 @@
+/// An endless xorshift sequence, the same on every run so that the benchmarks compare
+/// like with like.
+pub fn xorshift() -> impl Iterator<Item = u64> {
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    std::iter::repeat_with(move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    })
+}
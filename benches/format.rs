@@ -0,0 +1,60 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::fmt::Write;
+use ux::{i12, u12};
+
+const LEN: usize = 1024;
+
+// Formats values with `Display` and `LowerHex`, and the same values as their backing
+// type to show what formatting without masking would cost.
+macro_rules! bench_format {
+    ($c:ident, $($name:ident: $type:ident, $values:expr),*) => {$(
+        let values: Vec<$name> = $values.map($name::new).collect();
+        let raw: Vec<$type> = $values.collect();
+        let mut group = $c.benchmark_group(stringify!($name));
+        let mut buffer = String::with_capacity(8 * LEN);
+        group.bench_function("display", |b| {
+            b.iter(|| {
+                buffer.clear();
+                for value in black_box(&values) {
+                    write!(buffer, "{value}").unwrap();
+                }
+            })
+        });
+        group.bench_function(concat!("display/", stringify!($type)), |b| {
+            b.iter(|| {
+                buffer.clear();
+                for value in black_box(&raw) {
+                    write!(buffer, "{value}").unwrap();
+                }
+            })
+        });
+        group.bench_function("lower_hex", |b| {
+            b.iter(|| {
+                buffer.clear();
+                for value in black_box(&values) {
+                    write!(buffer, "{value:x}").unwrap();
+                }
+            })
+        });
+        group.bench_function(concat!("lower_hex/", stringify!($type)), |b| {
+            b.iter(|| {
+                buffer.clear();
+                for value in black_box(&raw) {
+                    write!(buffer, "{value:x}").unwrap();
+                }
+            })
+        });
+        group.finish();
+    )*};
+}
+
+fn format(c: &mut Criterion) {
+    bench_format!(
+        c,
+        u12: u16, (0..LEN).map(|i| (i * 61 % 4096) as u16),
+        i12: i16, (0..LEN).map(|i| (i * 61 % 4096) as i16 - 2048)
+    );
+}
+
+criterion_group!(benches, format);
+criterion_main!(benches);
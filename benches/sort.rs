@@ -0,0 +1,37 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use ux::u5;
+
+mod common;
+
+const LEN: usize = 1_000_000;
+
+// Sorts a million `u5` values, and the same values as plain `u8` to show what comparing
+// the backing values without masking them would cost.
+fn sort(c: &mut Criterion) {
+    let raw: Vec<u8> = common::xorshift()
+        .take(LEN)
+        .map(|x| (x % 32) as u8)
+        .collect();
+    let values: Vec<u5> = raw.iter().map(|&x| u5::new(x)).collect();
+
+    let mut group = c.benchmark_group("sort_unstable");
+    group.sample_size(20);
+    group.bench_function("u5", |b| {
+        b.iter_batched(
+            || values.clone(),
+            |mut values| values.sort_unstable(),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("u8", |b| {
+        b.iter_batched(
+            || raw.clone(),
+            |mut raw| raw.sort_unstable(),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, sort);
+criterion_main!(benches);
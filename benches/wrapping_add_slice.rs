@@ -0,0 +1,43 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ux::{i7, u12};
+
+const LEN: usize = 1 << 16;
+
+fn wrapping_add_slice(c: &mut Criterion) {
+    let src: Vec<u12> = (0..LEN).map(|i| u12::new((i * 61 % 4096) as u16)).collect();
+    let dst: Vec<u12> = (0..LEN).map(|i| u12::new((i * 13 % 4096) as u16)).collect();
+    let mut group = c.benchmark_group("u12");
+    group.bench_function("wrapping_add_slice", |b| {
+        let mut dst = dst.clone();
+        b.iter(|| u12::wrapping_add_slice(black_box(&mut dst), black_box(&src)))
+    });
+    group.bench_function("scalar", |b| {
+        let mut dst = dst.clone();
+        b.iter(|| {
+            for (d, s) in black_box(&mut dst).iter_mut().zip(black_box(&src)) {
+                *d = d.wrapping_add(*s);
+            }
+        })
+    });
+    group.finish();
+
+    let src: Vec<i7> = (0..LEN).map(|i| i7::new((i % 128) as i8 - 64)).collect();
+    let dst: Vec<i7> = (0..LEN).map(|i| i7::new(63 - (i % 128) as i8)).collect();
+    let mut group = c.benchmark_group("i7");
+    group.bench_function("wrapping_add_slice", |b| {
+        let mut dst = dst.clone();
+        b.iter(|| i7::wrapping_add_slice(black_box(&mut dst), black_box(&src)))
+    });
+    group.bench_function("scalar", |b| {
+        let mut dst = dst.clone();
+        b.iter(|| {
+            for (d, s) in black_box(&mut dst).iter_mut().zip(black_box(&src)) {
+                *d = d.wrapping_add(*s);
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, wrapping_add_slice);
+criterion_main!(benches);
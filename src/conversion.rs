@@ -1,29 +1,116 @@
 use crate::*;
 
 /// The error type returned when a checked integral type conversion fails.
+///
+/// The error records the offending value together with the name and range of
+/// the target type, so that it can be reported as eg.
+/// `500 out of range for u5 (0..=31)`.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub struct TryFromIntError(pub(crate) ());
+pub struct TryFromIntError {
+    conversion: Option<Conversion>,
+}
 
-impl From<lib::core::num::TryFromIntError> for TryFromIntError {
-    fn from(_: lib::core::num::TryFromIntError) -> TryFromIntError {
-        TryFromIntError(())
-    }
+/// The details of a failed conversion, unknown for errors converted from
+/// [`core::num::TryFromIntError`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Conversion {
+    value: i128,
+    target: &'static str,
+    min: i128,
+    max: i128,
 }
 
-impl From<lib::core::convert::Infallible> for TryFromIntError {
-    fn from(_: lib::core::convert::Infallible) -> TryFromIntError {
-        TryFromIntError(())
+impl TryFromIntError {
+    pub(crate) fn new<T: Wide, R: Wide>(value: T, target: &'static str, min: R, max: R) -> Self {
+        TryFromIntError {
+            conversion: Some(Conversion {
+                value: value.wide(),
+                target,
+                min: min.wide(),
+                max: max.wide(),
+            }),
+        }
+    }
+
+    /// Returns the value that could not be converted, or `None` if the error was
+    /// converted from [`core::num::TryFromIntError`].
+    ///
+    /// Values above `i128::MAX`, which can only come from a `u128`, are reported as
+    /// `i128::MAX`.
+    pub fn value(&self) -> Option<i128> {
+        self.conversion.map(|c| c.value)
+    }
+
+    /// Returns the name of the type the conversion was attempted into, or `None` if
+    /// the error was converted from [`core::num::TryFromIntError`].
+    pub fn target(&self) -> Option<&'static str> {
+        self.conversion.map(|c| c.target)
+    }
+
+    /// Returns the smallest value representable by the target type, or `None` if the
+    /// error was converted from [`core::num::TryFromIntError`].
+    pub fn min(&self) -> Option<i128> {
+        self.conversion.map(|c| c.min)
+    }
+
+    /// Returns the largest value representable by the target type, or `None` if the
+    /// error was converted from [`core::num::TryFromIntError`].
+    pub fn max(&self) -> Option<i128> {
+        self.conversion.map(|c| c.max)
     }
 }
 
 impl Display for TryFromIntError {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        write!(f, "out of range integral type conversion attempted")
+        match self.conversion {
+            Some(c) => write!(
+                f,
+                "{} out of range for {} ({}..={})",
+                c.value, c.target, c.min, c.max
+            ),
+            None => f.write_str("out of range integral type conversion attempted"),
+        }
+    }
+}
+
+impl core::error::Error for TryFromIntError {}
+
+/// Allows `?` on the conversions between built in types in functions returning a
+/// `TryFromIntError`.
+///
+/// The standard library error carries neither the offending value nor the target type,
+/// so all the getters of the result return `None`. It displays as the standard library
+/// error does.
+impl From<core::num::TryFromIntError> for TryFromIntError {
+    fn from(_: core::num::TryFromIntError) -> Self {
+        TryFromIntError { conversion: None }
+    }
+}
+
+/// Allows `?` on conversions that cannot fail, such as the `TryFrom` implementations
+/// derived from `From`, in functions returning a `TryFromIntError`.
+impl From<core::convert::Infallible> for TryFromIntError {
+    fn from(x: core::convert::Infallible) -> Self {
+        match x {}
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for TryFromIntError {}
+/// Lossless widening of every integer type taking part in conversions into `i128`.
+pub(crate) trait Wide: Copy {
+    fn wide(self) -> i128;
+}
+
+macro_rules! implement_wide {
+    {[$($type:ident),*]} => {$(
+        impl Wide for $type {
+            fn wide(self) -> i128 {
+                self as i128
+            }
+        }
+    )*};
+}
+
+implement_wide!([u8, u16, u32, u64, usize, i8, i16, i32, i64, i128]);
 
 // Only implement if $from can be converted into $name lossless
 macro_rules! implement_from {
@@ -48,13 +135,15 @@ macro_rules! implement_try_from {
             type Error = TryFromIntError;
 
             fn try_from(x: $from) -> Result<$name, Self::Error> {
+                let error = TryFromIntError::new(x, stringify!($name), $name::MIN, $name::MAX);
+
                 // First get the value into the correct type
-                let value = x.try_into()?;
+                let value = x.try_into().map_err(|_| error)?;
 
-                if value <= $name::MAX.into() && value >= $name::MIN.into() {
+                if ($name::MIN.0..=$name::MAX.0).contains(&value) {
                     Ok($name(value))
                 } else {
-                    Err(TryFromIntError(()))
+                    Err(error)
                 }
             }
         }
@@ -81,7 +170,8 @@ macro_rules! implement_try_into {
             type Error = TryFromIntError;
 
             fn try_from(x: $name) -> Result<$into, Self::Error> {
-                Ok($into::try_from(x.0)?)
+                $into::try_from(x.mask().0)
+                    .map_err(|_| TryFromIntError::new(x, stringify!($into), $into::MIN, $into::MAX))
             }
         }
     };
@@ -1743,6 +1833,53 @@ implement_from!(
     ]
 );
 
+// Reinterpret the two's complement bit pattern between the signed and unsigned type of the same
+// width
+macro_rules! implement_bit_cast {
+    {[$($unsigned:ident),*], [$($signed:ident),*]} => {$(
+        impl From<$signed> for $unsigned {
+            /// Reinterprets the bits of a signed value as the unsigned type of the same
+            /// width. This preserves the bit pattern, not the value, like `as` does for the
+            /// built in types.
+            fn from(x: $signed) -> $unsigned {
+                $unsigned(x.mask().0 as _).mask()
+            }
+        }
+
+        impl From<$unsigned> for $signed {
+            /// Reinterprets the bits of an unsigned value as the signed type of the same
+            /// width. This preserves the bit pattern, not the value, like `as` does for the
+            /// built in types.
+            fn from(x: $unsigned) -> $signed {
+                $signed(x.mask().0 as _).mask()
+            }
+        }
+    )*};
+}
+
+implement_bit_cast!(
+    [
+        u1, u2, u3, u4, u5, u6, u7, u9, u10, u11, u12, u13, u14, u15, u17, u18, u19, u20, u21, u22,
+        u23, u24, u25, u26, u27, u28, u29, u30, u31, u33, u34, u35, u36, u37, u38, u39, u40, u41,
+        u42, u43, u44, u45, u46, u47, u48, u49, u50, u51, u52, u53, u54, u55, u56, u57, u58, u59,
+        u60, u61, u62, u63, u65, u66, u67, u68, u69, u70, u71, u72, u73, u74, u75, u76, u77, u78,
+        u79, u80, u81, u82, u83, u84, u85, u86, u87, u88, u89, u90, u91, u92, u93, u94, u95, u96,
+        u97, u98, u99, u100, u101, u102, u103, u104, u105, u106, u107, u108, u109, u110, u111,
+        u112, u113, u114, u115, u116, u117, u118, u119, u120, u121, u122, u123, u124, u125, u126,
+        u127
+    ],
+    [
+        i1, i2, i3, i4, i5, i6, i7, i9, i10, i11, i12, i13, i14, i15, i17, i18, i19, i20, i21, i22,
+        i23, i24, i25, i26, i27, i28, i29, i30, i31, i33, i34, i35, i36, i37, i38, i39, i40, i41,
+        i42, i43, i44, i45, i46, i47, i48, i49, i50, i51, i52, i53, i54, i55, i56, i57, i58, i59,
+        i60, i61, i62, i63, i65, i66, i67, i68, i69, i70, i71, i72, i73, i74, i75, i76, i77, i78,
+        i79, i80, i81, i82, i83, i84, i85, i86, i87, i88, i89, i90, i91, i92, i93, i94, i95, i96,
+        i97, i98, i99, i100, i101, i102, i103, i104, i105, i106, i107, i108, i109, i110, i111,
+        i112, i113, i114, i115, i116, i117, i118, i119, i120, i121, i122, i123, i124, i125, i126,
+        i127
+    ]
+);
+
 impl From<bool> for u1 {
     fn from(b: bool) -> Self {
         match b {
@@ -1791,6 +1928,10 @@ mod tests {
     }
 
     #[test]
+    #[allow(
+        clippy::unnecessary_fallible_conversions,
+        clippy::unusual_byte_groupings
+    )]
     fn test_fallible_conversion_unsigned() {
         assert_eq!(u16::try_from(u9(12)), Ok(12u16));
         assert_eq!(u32::try_from(u9(12)), Ok(12u32));
@@ -1817,6 +1958,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::unnecessary_fallible_conversions)]
     fn test_fallible_conversion_signed() {
         assert_eq!(i16::try_from(i9(12)), Ok(12i16));
         assert_eq!(i32::try_from(i9(12)), Ok(12i32));
@@ -1839,12 +1981,172 @@ mod tests {
         assert!(i6::try_from(i7(-64)).is_err());
     }
 
+    #[test]
+    fn test_fallible_conversion_error_unsigned() {
+        let error = u5::try_from(500u32).unwrap_err();
+        assert_eq!(error.value(), Some(500));
+        assert_eq!(error.target(), Some("u5"));
+        assert_eq!(error.min(), Some(0));
+        assert_eq!(error.max(), Some(31));
+
+        let error = u33::try_from(u64::MAX).unwrap_err();
+        assert_eq!(error.value(), Some(u64::MAX as i128));
+        assert_eq!(error.max(), Some(0x1_ffff_ffff));
+
+        let error = u8::try_from(u9(300)).unwrap_err();
+        assert_eq!(error.value(), Some(300));
+        assert_eq!(error.target(), Some("u8"));
+        assert_eq!(error.max(), Some(255));
+    }
+
+    #[test]
+    fn test_fallible_conversion_error_signed() {
+        let error = i5::try_from(-100i64).unwrap_err();
+        assert_eq!(error.value(), Some(-100));
+        assert_eq!(error.target(), Some("i5"));
+        assert_eq!(error.min(), Some(-16));
+        assert_eq!(error.max(), Some(15));
+
+        let error = i6::try_from(i7(-64)).unwrap_err();
+        assert_eq!(error.value(), Some(-64));
+        assert_eq!(error.min(), Some(-32));
+        assert_eq!(error.max(), Some(31));
+    }
+
+    #[test]
+    fn test_bit_cast() {
+        assert_eq!(u4::from(i4::new(-1)), u4::new(15));
+        assert_eq!(u4::from(i4::MIN), u4::new(8));
+        assert_eq!(u4::from(i4::new(7)), u4::new(7));
+        assert_eq!(i4::from(u4::new(15)), i4::new(-1));
+        assert_eq!(i4::from(u4::new(8)), i4::MIN);
+        assert_eq!(i4::from(u4::new(7)), i4::new(7));
+
+        assert_eq!(u1::from(i1::new(-1)), u1::new(1));
+        assert_eq!(u63::from(i63::new(-1)), u63::MAX);
+        assert_eq!(i127::from(u127::MAX), i127::new(-1));
+
+        for bits in 0..=0xfffu16 {
+            assert_eq!(u12::from(i12::from(u12(bits))), u12(bits));
+        }
+        for value in i12::MIN.0..=i12::MAX.0 {
+            assert_eq!(i12::from(u12::from(i12(value))), i12(value));
+            assert_eq!(u12::from(i12(value)), u12(value as u16 & 0xfff));
+        }
+    }
+
+    #[test]
+    fn test_date_bitfield() {
+        // A FAT style date: years since 1980 in bits 9..16, the month in bits 5..9 and
+        // the day in bits 0..5.
+        fn pack(year: u16, month: u8, day: u8) -> Result<u16, TryFromIntError> {
+            let year = u7::new_from(i32::from(year) - 1980)?;
+            let month = u4::try_from(month)?;
+            let day = u5::try_from(day)?;
+            Ok(u16::from(year) << 9 | u16::from(month) << 5 | u16::from(day))
+        }
+
+        fn unpack(date: u16) -> (u7, u4, u5) {
+            (
+                u7::try_from(date >> 9).unwrap(),
+                u4::try_from((date >> 5) & 0xf).unwrap(),
+                u5::try_from(date & 0x1f).unwrap(),
+            )
+        }
+
+        let date = pack(2024, 2, 29).unwrap();
+        assert_eq!(date, 44 << 9 | 2 << 5 | 29);
+        assert_eq!(unpack(date), (u7::new(44), u4::new(2), u5::new(29)));
+        assert_eq!(
+            unpack(pack(2107, 15, 31).unwrap()),
+            (u7::MAX, u4::MAX, u5::MAX)
+        );
+        assert_eq!(
+            unpack(pack(1980, 0, 0).unwrap()),
+            (u7::MIN, u4::MIN, u5::MIN)
+        );
+
+        let error = pack(2024, 2, 32).unwrap_err();
+        assert_eq!(
+            (error.target(), error.value(), error.max()),
+            (Some("u5"), Some(32), Some(31))
+        );
+        let error = pack(2024, 16, 1).unwrap_err();
+        assert_eq!(
+            (error.target(), error.value(), error.max()),
+            (Some("u4"), Some(16), Some(15))
+        );
+        let error = pack(2108, 1, 1).unwrap_err();
+        assert_eq!(
+            (error.target(), error.value(), error.max()),
+            (Some("u7"), Some(128), Some(127))
+        );
+        let error = pack(1979, 1, 1).unwrap_err();
+        assert_eq!(
+            (error.target(), error.value(), error.min()),
+            (Some("u7"), Some(-1), Some(0))
+        );
+
+        // The field only bounds the month by its width; 13 fits in four bits, so
+        // calendar validation is left to the caller.
+        assert_eq!(u4::try_from(13u8), Ok(u4::new(13)));
+    }
+
+    #[test]
+    fn error_from_core() {
+        fn narrow(x: u32) -> Result<u3, TryFromIntError> {
+            let byte = u8::try_from(x)?;
+            u3::try_from(byte)
+        }
+
+        assert_eq!(narrow(5), Ok(u3::new(5)));
+        assert_eq!(narrow(9).unwrap_err().target(), Some("u3"));
+
+        let error = narrow(300).unwrap_err();
+        assert_eq!(
+            (error.target(), error.value(), error.min(), error.max()),
+            (None, None, None, None)
+        );
+        #[cfg(feature = "std")]
+        assert_eq!(
+            error.to_string(),
+            "out of range integral type conversion attempted"
+        );
+    }
+
+    #[test]
+    fn error_from_infallible() {
+        // Goes through `TryInto` generically, as code that does not know the
+        // conversion is lossless would.
+        fn widen<T: TryInto<u8>>(x: T) -> Result<u8, T::Error> {
+            x.try_into()
+        }
+
+        fn widen_and_narrow(x: u4) -> Result<u3, TryFromIntError> {
+            let wide = widen(x)?;
+            u3::try_from(wide)
+        }
+
+        assert_eq!(widen_and_narrow(u4::new(5)), Ok(u3::new(5)));
+        let error = widen_and_narrow(u4::new(9)).unwrap_err();
+        assert_eq!((error.value(), error.target()), (Some(9), Some("u3")));
+
+        fn source(error: &dyn core::error::Error) -> bool {
+            error.source().is_none()
+        }
+        assert!(source(&error));
+    }
+
     #[test]
     #[cfg(feature = "std")]
     fn error_trait() {
+        let error = u5::try_from(500u32).unwrap_err();
         assert_eq!(
-            (&TryFromIntError(()) as &dyn std::error::Error).to_string(),
-            "out of range integral type conversion attempted"
+            (&error as &dyn std::error::Error).to_string(),
+            "500 out of range for u5 (0..=31)"
         );
+
+        let error = i5::try_from(-100i64).unwrap_err();
+        assert_eq!(error.to_string(), "-100 out of range for i5 (-16..=15)");
     }
 }
@@ -0,0 +1,129 @@
+//! Conversions between the uX types and the fixed-point types of the `fixed` crate.
+//!
+//! A uX type converts to and from the fixed-point type with the same backing primitive
+//! whose integer part is exactly as wide as the uX type. For `u12`, which is stored in a
+//! `u16`, that is `FixedU16<U4>`: 12 integer bits followed by 4 fractional bits. The uX
+//! value occupies the integer bits and the fractional bits are zero, so
+//!
+//! - `From<u12> for FixedU16<U4>` is lossless, and
+//! - `From<FixedU16<U4>> for u12` returns the integer part, discarding the fractional
+//!   bits and thereby rounding towards negative infinity like `fixed`'s own `int`.
+//!
+//! Since the integer parts have the same width neither direction can overflow. Fixed-point
+//! types with a different number of integer bits are not converted to; go through one of
+//! the built in types with `to_num` and `from_num` for those.
+
+use crate::*;
+
+use ::fixed::types::extra::*;
+use ::fixed::{
+    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
+    FixedU8,
+};
+
+macro_rules! implement_fixed {
+    ($fixed:ident: $($name:ident => $frac:ident),*) => {$(
+        impl From<$name> for $fixed<$frac> {
+            fn from(x: $name) -> Self {
+                $fixed::from_bits(x.mask().0 << <$fixed<$frac>>::FRAC_NBITS)
+            }
+        }
+
+        impl From<$fixed<$frac>> for $name {
+            fn from(x: $fixed<$frac>) -> Self {
+                $name(x.to_bits() >> <$fixed<$frac>>::FRAC_NBITS).mask()
+            }
+        }
+    )*};
+}
+
+implement_fixed!(FixedU8:
+    u1 => U7, u2 => U6, u3 => U5, u4 => U4, u5 => U3, u6 => U2, u7 => U1
+);
+implement_fixed!(FixedU16:
+    u9 => U7, u10 => U6, u11 => U5, u12 => U4, u13 => U3, u14 => U2, u15 => U1
+);
+implement_fixed!(FixedU32:
+    u17 => U15, u18 => U14, u19 => U13, u20 => U12, u21 => U11, u22 => U10, u23 => U9,
+    u24 => U8, u25 => U7, u26 => U6, u27 => U5, u28 => U4, u29 => U3, u30 => U2, u31 => U1
+);
+implement_fixed!(FixedU64:
+    u33 => U31, u34 => U30, u35 => U29, u36 => U28, u37 => U27, u38 => U26, u39 => U25,
+    u40 => U24, u41 => U23, u42 => U22, u43 => U21, u44 => U20, u45 => U19, u46 => U18,
+    u47 => U17, u48 => U16, u49 => U15, u50 => U14, u51 => U13, u52 => U12, u53 => U11,
+    u54 => U10, u55 => U9, u56 => U8, u57 => U7, u58 => U6, u59 => U5, u60 => U4, u61 => U3,
+    u62 => U2, u63 => U1
+);
+implement_fixed!(FixedU128:
+    u65 => U63, u66 => U62, u67 => U61, u68 => U60, u69 => U59, u70 => U58, u71 => U57,
+    u72 => U56, u73 => U55, u74 => U54, u75 => U53, u76 => U52, u77 => U51, u78 => U50,
+    u79 => U49, u80 => U48, u81 => U47, u82 => U46, u83 => U45, u84 => U44, u85 => U43,
+    u86 => U42, u87 => U41, u88 => U40, u89 => U39, u90 => U38, u91 => U37, u92 => U36,
+    u93 => U35, u94 => U34, u95 => U33, u96 => U32, u97 => U31, u98 => U30, u99 => U29,
+    u100 => U28, u101 => U27, u102 => U26, u103 => U25, u104 => U24, u105 => U23, u106 => U22,
+    u107 => U21, u108 => U20, u109 => U19, u110 => U18, u111 => U17, u112 => U16, u113 => U15,
+    u114 => U14, u115 => U13, u116 => U12, u117 => U11, u118 => U10, u119 => U9, u120 => U8,
+    u121 => U7, u122 => U6, u123 => U5, u124 => U4, u125 => U3, u126 => U2, u127 => U1
+);
+
+implement_fixed!(FixedI8:
+    i1 => U7, i2 => U6, i3 => U5, i4 => U4, i5 => U3, i6 => U2, i7 => U1
+);
+implement_fixed!(FixedI16:
+    i9 => U7, i10 => U6, i11 => U5, i12 => U4, i13 => U3, i14 => U2, i15 => U1
+);
+implement_fixed!(FixedI32:
+    i17 => U15, i18 => U14, i19 => U13, i20 => U12, i21 => U11, i22 => U10, i23 => U9,
+    i24 => U8, i25 => U7, i26 => U6, i27 => U5, i28 => U4, i29 => U3, i30 => U2, i31 => U1
+);
+implement_fixed!(FixedI64:
+    i33 => U31, i34 => U30, i35 => U29, i36 => U28, i37 => U27, i38 => U26, i39 => U25,
+    i40 => U24, i41 => U23, i42 => U22, i43 => U21, i44 => U20, i45 => U19, i46 => U18,
+    i47 => U17, i48 => U16, i49 => U15, i50 => U14, i51 => U13, i52 => U12, i53 => U11,
+    i54 => U10, i55 => U9, i56 => U8, i57 => U7, i58 => U6, i59 => U5, i60 => U4, i61 => U3,
+    i62 => U2, i63 => U1
+);
+implement_fixed!(FixedI128:
+    i65 => U63, i66 => U62, i67 => U61, i68 => U60, i69 => U59, i70 => U58, i71 => U57,
+    i72 => U56, i73 => U55, i74 => U54, i75 => U53, i76 => U52, i77 => U51, i78 => U50,
+    i79 => U49, i80 => U48, i81 => U47, i82 => U46, i83 => U45, i84 => U44, i85 => U43,
+    i86 => U42, i87 => U41, i88 => U40, i89 => U39, i90 => U38, i91 => U37, i92 => U36,
+    i93 => U35, i94 => U34, i95 => U33, i96 => U32, i97 => U31, i98 => U30, i99 => U29,
+    i100 => U28, i101 => U27, i102 => U26, i103 => U25, i104 => U24, i105 => U23, i106 => U22,
+    i107 => U21, i108 => U20, i109 => U19, i110 => U18, i111 => U17, i112 => U16, i113 => U15,
+    i114 => U14, i115 => U13, i116 => U12, i117 => U11, i118 => U10, i119 => U9, i120 => U8,
+    i121 => U7, i122 => U6, i123 => U5, i124 => U4, i125 => U3, i126 => U2, i127 => U1
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_round_trip() {
+        for value in 0..=u12::MAX.0 {
+            let x = u12::new(value);
+            let fixed = FixedU16::<U4>::from(x);
+            assert_eq!(fixed.to_bits(), value << 4);
+            assert_eq!(u12::from(fixed), x);
+        }
+
+        for value in -2048..=2047 {
+            let x = i12::new(value);
+            let fixed = FixedI16::<U4>::from(x);
+            assert_eq!(fixed, FixedI16::<U4>::from_num(value));
+            assert_eq!(i12::from(fixed), x);
+        }
+
+        assert_eq!(u127::from(FixedU128::<U1>::from(u127::MAX)), u127::MAX);
+        assert_eq!(i1::from(FixedI8::<U7>::from(i1::MIN)), i1::MIN);
+    }
+
+    #[test]
+    fn test_fixed_integer_part() {
+        assert_eq!(u12::from(FixedU16::<U4>::from_num(100.9375)), u12::new(100));
+        assert_eq!(i12::from(FixedI16::<U4>::from_num(-3.5)), i12::new(-4));
+        assert_eq!(i12::from(FixedI16::<U4>::from_num(3.5)), i12::new(3));
+        assert_eq!(u63::from(FixedU64::<U1>::MAX), u63::MAX);
+    }
+}
@@ -0,0 +1,87 @@
+/// Defines a set of named boolean flags stored in one of the uX types.
+///
+/// Each flag is given the index of the bit that stores it, so a set of three flags
+/// can be stored in an `u3` without wasting any bits. The generated type has an
+/// associated constant per flag and `get`, `set` and `toggle` methods taking one of
+/// those constants.
+///
+/// # Examples
+///
+/// ```
+/// use ux::{packed_flags, u3};
+///
+/// packed_flags! {
+///     /// Access permissions of a file.
+///     pub struct Permissions: u3 {
+///         const READ = 0;
+///         const WRITE = 1;
+///         const EXECUTE = 2;
+///     }
+/// }
+///
+/// let mut permissions = Permissions::empty();
+/// permissions.set(Permissions::READ, true);
+/// permissions.toggle(Permissions::EXECUTE);
+///
+/// assert!(permissions.get(Permissions::READ));
+/// assert!(!permissions.get(Permissions::WRITE));
+/// assert_eq!(permissions.bits(), u3::new(0b101));
+/// ```
+#[macro_export]
+macro_rules! packed_flags {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident: $inner:ty {
+            $(
+                $(#[$flag_attr:meta])*
+                const $flag:ident = $bit:expr;
+            )*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        $vis struct $name($inner);
+
+        #[allow(dead_code)]
+        impl $name {
+            $(
+                $(#[$flag_attr])*
+                pub const $flag: Self = $name(<$inner>::new(1 << $bit));
+            )*
+
+            /// Returns a set with all flags cleared.
+            pub fn empty() -> Self {
+                $name(<$inner>::new(0))
+            }
+
+            /// Creates a set from the bits storing the flags.
+            pub fn from_bits(bits: $inner) -> Self {
+                $name(bits)
+            }
+
+            /// Returns the bits storing the flags.
+            pub fn bits(self) -> $inner {
+                self.0
+            }
+
+            /// Returns `true` if `flag` is set.
+            pub fn get(self, flag: Self) -> bool {
+                self.0 & flag.0 == flag.0
+            }
+
+            /// Sets `flag` to `value`.
+            pub fn set(&mut self, flag: Self, value: bool) {
+                if value {
+                    self.0 |= flag.0;
+                } else {
+                    self.0 &= !flag.0;
+                }
+            }
+
+            /// Inverts `flag`.
+            pub fn toggle(&mut self, flag: Self) {
+                self.0 ^= flag.0;
+            }
+        }
+    };
+}
@@ -0,0 +1,44 @@
+//! Conversions into the half precision floating point type of the `half` crate.
+//!
+//! An `f16` has an 11-bit significand, so it represents every integer up to 2^11
+//! exactly. The conversion is therefore only implemented for the unsigned types of at
+//! most 11 bits, `u1` to `u11`, where it is lossless. Values of wider types may
+//! round; use `f16::from_f32` explicitly if that is acceptable.
+
+use crate::*;
+
+use ::half::f16;
+
+macro_rules! implement_f16 {
+    ($($name:ident),*) => {$(
+        impl From<$name> for f16 {
+            fn from(x: $name) -> Self {
+                f16::from_f32(f32::from(x.mask().0))
+            }
+        }
+    )*};
+}
+
+implement_f16!(u1, u2, u3, u4, u5, u6, u7, u9, u10, u11);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f16_from_u10() {
+        for value in 0..=u10::MAX.0 {
+            let float = f16::from(u10::new(value));
+            assert_eq!(float.to_f32(), f32::from(value));
+            assert_eq!(float.to_f32() as u16, value);
+        }
+    }
+
+    #[test]
+    fn test_f16_from() {
+        assert_eq!(f16::from(u1::MAX), f16::ONE);
+        assert_eq!(f16::from(u7::MAX).to_f32(), 127.0);
+        assert_eq!(f16::from(u11::MAX).to_f32(), 2047.0);
+        assert_eq!(f16::from(u11::MIN), f16::ZERO);
+    }
+}
@@ -0,0 +1,58 @@
+/// Functionality shared by all the integer types defined in this crate.
+///
+/// The trait is object safe, so values of different widths can be stored behind
+/// `&dyn UxInteger` or `Box<dyn UxInteger>` and still be inspected.
+/// Methods taking `self` by value require `Self: Sized` and are only available
+/// on the concrete types.
+pub trait UxInteger {
+    /// Returns the width of the integer type in bits.
+    fn width(&self) -> u32;
+
+    /// Returns the value as an `u128`.
+    ///
+    /// Negative values are sign extended, like `as u128` does for the built in types.
+    fn as_u128(&self) -> u128;
+
+    /// Returns the value as an `i128`.
+    ///
+    /// All the integer types of this crate fit losslessly into an `i128`.
+    fn as_i128(&self) -> i128;
+
+    /// Checked integer division. Computes `self / rhs`, returning `None` if `rhs == 0`
+    /// or the division results in overflow.
+    fn checked_div(self, rhs: Self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Checked integer remainder. Computes `self % rhs`, returning `None` if `rhs == 0`
+    /// or the division results in overflow.
+    fn checked_rem(self, rhs: Self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Creates a value from `value`, clamping it to the range of the integer type.
+    fn saturating_from_i128(value: i128) -> Self
+    where
+        Self: Sized;
+}
+
+pub(crate) mod sealed {
+    pub trait Sealed {}
+}
+
+/// Type level marker of whether an integer type of this crate is signed.
+///
+/// Every type implements either [`Signed`] or [`Unsigned`] as well, so generic code
+/// can be restricted to one of them, or branch on [`Signedness::SIGNED`] to eg. decide
+/// whether to sign extend. The trait is sealed and can not be implemented outside of
+/// this crate.
+pub trait Signedness: sealed::Sealed {
+    /// `true` for the signed types and `false` for the unsigned types.
+    const SIGNED: bool;
+}
+
+/// Marker implemented by the signed integer types of this crate.
+pub trait Signed: Signedness {}
+
+/// Marker implemented by the unsigned integer types of this crate.
+pub trait Unsigned: Signedness {}
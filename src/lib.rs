@@ -18,7 +18,24 @@ mod lib {
     pub use core;
 }
 
+#[cfg(feature = "num-bigint")]
+mod bigint;
+#[cfg(feature = "bitvec")]
+mod bitslice;
 mod conversion;
+#[cfg(feature = "fixed")]
+mod fixed_point;
+mod flags;
+#[cfg(feature = "half")]
+mod float16;
+mod integer;
+mod parse;
+#[cfg(feature = "serde")]
+mod serialization;
+
+pub use conversion::TryFromIntError;
+pub use integer::{Signed, Signedness, Unsigned, UxInteger};
+pub use parse::{IntErrorKind, ParseIntError};
 
 use lib::core::ops::{
     BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign, Shr,
@@ -45,12 +62,195 @@ macro_rules! define_unsigned {
             pub const MIN: Self = $name(0);
 
             fn mask(self) -> Self {
-                $name(self.0 & ( ((1 as $type) << $bits).overflowing_sub(1).0))
+                $name(self.0 & Self::MASK)
+            }
+
+            /// Modular exponentiation. Computes `self.pow(exp) % modulus` without
+            /// overflowing, using exponentiation by squaring.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if `modulus` is zero.
+            pub fn mod_pow(self, exp: Self, modulus: Self) -> Self {
+                let modulus = modulus.mask().0;
+                assert!(modulus != 0, "attempt to calculate the remainder with a divisor of zero");
+
+                let mul_mod = |mut a: $type, mut b: $type| -> $type {
+                    if Self::BITS <= 64 {
+                        // The product of two residues below 2^64 fits into an `u128`.
+                        return (a as u128 * b as u128 % modulus as u128) as $type;
+                    }
+                    // Wider residues are multiplied by shift and add. The backing type
+                    // always has at least one bit to spare, so the sum of two residues
+                    // never overflows it.
+                    let mut product = 0;
+                    while b != 0 {
+                        if b & 1 == 1 {
+                            product = (product + a) % modulus;
+                        }
+                        a = (a + a) % modulus;
+                        b >>= 1;
+                    }
+                    product
+                };
+
+                let mut base = self.mask().0 % modulus;
+                let mut exp = exp.mask().0;
+                let mut result = 1 % modulus;
+                while exp != 0 {
+                    if exp & 1 == 1 {
+                        result = mul_mod(result, base);
+                    }
+                    base = mul_mod(base, base);
+                    exp >>= 1;
+                }
+                $name(result)
+            }
+
+            /// Calculates the smallest value greater than or equal to `self` that is a
+            /// multiple of `rhs`. Returns `None` if `rhs` is zero or the operation would
+            /// result in overflow.
+            pub fn checked_next_multiple_of(self, rhs: Self) -> Option<Self> {
+                let (value, rhs) = (self.mask().0, rhs.mask().0);
+                if rhs == 0 {
+                    return None;
+                }
+                // Both terms are below 2^BITS, so the sum fits the backing type.
+                let next = match value % rhs {
+                    0 => value,
+                    r => value + (rhs - r),
+                };
+                if next <= Self::MAX.0 {
+                    Some($name(next))
+                } else {
+                    None
+                }
+            }
+
+            /// Calculates the smallest value greater than or equal to `self` that is a
+            /// multiple of `rhs`.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if `rhs` is zero, and in debug mode if the
+            /// operation results in overflow. In release mode the result wraps.
+            pub fn next_multiple_of(self, rhs: Self) -> Self {
+                assert!(rhs.mask().0 != 0, "attempt to calculate the remainder with a divisor of zero");
+                match self.checked_next_multiple_of(rhs) {
+                    Some(next) => next,
+                    None => {
+                        debug_assert!(false, "attempt to add with overflow");
+                        let (value, rhs) = (self.mask().0, rhs.mask().0);
+                        $name(value + (rhs - value % rhs)).mask()
+                    }
+                }
+            }
+
+            /// Calculates the largest value less than or equal to `self` that is a
+            /// multiple of `rhs`. Returns `None` if `rhs` is zero.
+            pub fn checked_prev_multiple_of(self, rhs: Self) -> Option<Self> {
+                let (value, rhs) = (self.mask().0, rhs.mask().0);
+                if rhs == 0 {
+                    None
+                } else {
+                    Some($name(value - value % rhs))
+                }
+            }
+
+            /// Calculates the largest value less than or equal to `self` that is a
+            /// multiple of `rhs`.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if `rhs` is zero.
+            pub fn prev_multiple_of(self, rhs: Self) -> Self {
+                match self.checked_prev_multiple_of(rhs) {
+                    Some(prev) => prev,
+                    None => panic!("attempt to calculate the remainder with a divisor of zero"),
+                }
+            }
+
+            /// Computes the modular multiplicative inverse of `self` modulo `modulus`, the
+            /// value `x < modulus` for which `self * x % modulus == 1`.
+            ///
+            /// Returns `None` if `modulus` is zero or `self` and `modulus` are not coprime,
+            /// in which case no inverse exists.
+            pub fn mod_inverse(self, modulus: Self) -> Option<Self> {
+                // Extended Euclidean algorithm. The Bezout coefficients are bounded by the
+                // modulus in magnitude, so they fit in an i128 for all widths.
+                let modulus = modulus.mask().0 as i128;
+                if modulus == 0 {
+                    return None;
+                }
+                let (mut old_r, mut r) = (self.mask().0 as i128 % modulus, modulus);
+                let (mut old_s, mut s) = (1i128, 0i128);
+                while r != 0 {
+                    let quotient = old_r / r;
+                    (old_r, r) = (r, old_r - quotient * r);
+                    (old_s, s) = (s, old_s - quotient * s);
+                }
+                if old_r == 1 {
+                    Some($name(old_s.rem_euclid(modulus) as $type))
+                } else {
+                    None
+                }
+            }
+
+            /// Returns `true` if and only if `self == 2^k` for some `k`.
+            pub fn is_power_of_two(self) -> bool {
+                self.mask().0.is_power_of_two()
+            }
+
+            /// Returns the smallest power of two greater than or equal to `self`. If the
+            /// next power of two is greater than `Self::MAX`, `None` is returned.
+            pub fn checked_next_power_of_two(self) -> Option<Self> {
+                self.mask()
+                    .0
+                    .checked_next_power_of_two()
+                    .filter(|&power| power <= Self::MAX.0)
+                    .map($name)
+            }
+
+            /// Returns the smallest power of two greater than or equal to `self`.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic in debug mode if the next power of two is greater
+            /// than `Self::MAX`. In release mode it returns zero.
+            pub fn next_power_of_two(self) -> Self {
+                let power = self.checked_next_power_of_two();
+                debug_assert!(power.is_some(), "attempt to add with overflow");
+                power.unwrap_or($name(0))
+            }
+
+            /// Returns `Self::MAX - self`, the number of times `self` can be incremented
+            /// before it overflows.
+            ///
+            /// This never overflows, which makes it convenient for computing the remaining
+            /// capacity of counters and ring buffer indices stored in a narrow field.
+            pub fn distance_to_max(self) -> Self {
+                $name(Self::MAX.0 - self.mask().0)
+            }
+
+            /// Returns `self - Self::MIN`, the number of times `self` can be decremented
+            /// before it overflows.
+            ///
+            /// As `Self::MIN` is zero for unsigned types, this is `self` itself.
+            pub fn distance_to_min(self) -> Self {
+                $name(self.mask().0 - Self::MIN.0)
             }
         }
 
         implement_common!($name, $bits, $type);
 
+        impl integer::sealed::Sealed for $name {}
+
+        impl Signedness for $name {
+            const SIGNED: bool = false;
+        }
+
+        impl Unsigned for $name {}
+
     }
 }
 
@@ -70,21 +270,114 @@ macro_rules! define_signed {
 
             fn mask(self) -> Self {
                 if ( self.0 & (1<<($bits-1)) ) == 0 {
-                    $name(self.0 & ( ((1 as $type) << $bits).overflowing_sub(1).0))
+                    $name(self.0 & Self::MASK)
                 } else {
-                    $name(self.0 | !( ((1 as $type) << $bits).overflowing_sub(1).0))
+                    $name(self.0 | !Self::MASK)
                 }
             }
         }
 
         implement_common!($name, $bits, $type);
 
+        impl integer::sealed::Sealed for $name {}
+
+        impl Signedness for $name {
+            const SIGNED: bool = true;
+        }
+
+        impl Signed for $name {}
+
+        impl $name {
+            /// Computes the absolute value of `self`.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic in debug mode if `self == Self::MIN`, as the
+            /// absolute value does not fit. In release mode it returns `Self::MIN`.
+            pub fn abs(self) -> Self {
+                debug_assert!(self != Self::MIN, "attempt to negate with overflow");
+                self.wrapping_abs()
+            }
+
+            /// Checked absolute value. Computes `self.abs()`, returning `None` if
+            /// `self == Self::MIN`.
+            pub fn checked_abs(self) -> Option<Self> {
+                if self == Self::MIN {
+                    None
+                } else {
+                    Some($name(self.mask().0.abs()))
+                }
+            }
+
+            /// Checked negation. Computes `-self`, returning `None` if `self == Self::MIN`.
+            pub fn checked_neg(self) -> Option<Self> {
+                if self == Self::MIN {
+                    None
+                } else {
+                    Some($name(-self.mask().0))
+                }
+            }
+
+            /// Wrapping absolute value. Computes `self.abs()`, wrapping around at the
+            /// boundary of the type so that `Self::MIN` is returned unchanged.
+            pub fn wrapping_abs(self) -> Self {
+                $name(self.mask().0.wrapping_abs()).mask()
+            }
+
+            /// Saturating absolute value. Computes `self.abs()`, returning `Self::MAX`
+            /// if `self == Self::MIN`.
+            pub fn saturating_abs(self) -> Self {
+                self.checked_abs().unwrap_or(Self::MAX)
+            }
+
+            /// Returns a number representing the sign of `self`: `0` if the number is zero,
+            /// `1` if it is positive and `-1` if it is negative.
+            pub fn signum(self) -> Self {
+                $name(self.mask().0.signum())
+            }
+
+            /// Returns `true` if `self` is positive and `false` if the number is zero or
+            /// negative.
+            pub fn is_positive(self) -> bool {
+                self.mask().0 > 0
+            }
+
+            /// Returns `true` if `self` is negative and `false` if the number is zero or
+            /// positive.
+            pub fn is_negative(self) -> bool {
+                self.mask().0 < 0
+            }
+        }
+
+        impl lib::core::ops::Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                debug_assert!(self != Self::MIN, "attempt to negate with overflow");
+                $name(self.mask().0.wrapping_neg()).mask()
+            }
+        }
+
+        impl<'a> lib::core::ops::Neg for &'a $name {
+            type Output = <$name as lib::core::ops::Neg>::Output;
+
+            fn neg(self) -> Self::Output {
+                -*self
+            }
+        }
     }
 }
 
 macro_rules! implement_common {
     ($name:ident, $bits:expr, $type:ident) => {
         impl $name {
+            /// The size of this integer type in bits.
+            pub const BITS: u32 = $bits;
+
+            /// The mask selecting the low `Self::BITS` bits of the backing type, which
+            /// hold the bits of this integer type.
+            pub const MASK: $type = ((1 as $type) << $bits).wrapping_sub(1);
+
             /// Returns the smallest value that can be represented by this integer type.
             pub fn min_value() -> $name {
                 $name::MIN
@@ -93,6 +386,16 @@ macro_rules! implement_common {
             pub fn max_value() -> $name {
                 $name::MAX
             }
+            /// Returns the smallest value that can be represented by this integer type,
+            /// as the backing primitive type.
+            pub const fn min_primitive() -> $type {
+                $name::MIN.0
+            }
+            /// Returns the largest value that can be represented by this integer type,
+            /// as the backing primitive type.
+            pub const fn max_primitive() -> $type {
+                $name::MAX.0
+            }
 
             /// Crates a new variable
             ///
@@ -118,6 +421,147 @@ macro_rules! implement_common {
                 $name(value)
             }
 
+            /// Creates a value from the low `Self::BITS` bits of `bits`.
+            ///
+            /// The bits above the width are ignored; for signed types bit `Self::BITS - 1`
+            /// is the sign bit.
+            pub fn from_bits(bits: $type) -> Self {
+                $name(bits).mask()
+            }
+
+            /// Creates a value from `bits`, verifying that the bits above the width are
+            /// clear for unsigned types, or copies of the sign bit `Self::BITS - 1` for
+            /// signed types.
+            ///
+            /// Returns an error if they are not, instead of discarding them as
+            /// [`from_bits`](Self::from_bits) does.
+            pub fn checked_from_bits(bits: $type) -> Result<Self, TryFromIntError> {
+                let value = $name(bits).mask();
+                if value.0 == bits {
+                    Ok(value)
+                } else {
+                    Err(TryFromIntError::new(
+                        bits.try_into().unwrap_or(i128::MAX),
+                        stringify!($name),
+                        Self::MIN,
+                        Self::MAX,
+                    ))
+                }
+            }
+
+            /// Creates a new variable from any value convertible into an `i128`,
+            /// such as all the built in integer types.
+            ///
+            /// Returns an error if `value` is not representable by this type.
+            pub fn new_from<T: TryInto<i128>>(value: T) -> Result<Self, TryFromIntError> {
+                match value.try_into() {
+                    Ok(value) if value >= Self::MIN.as_i128() && value <= Self::MAX.as_i128() => {
+                        Ok($name(value as $type))
+                    }
+                    Ok(value) => Err(TryFromIntError::new(
+                        value,
+                        stringify!($name),
+                        Self::MIN,
+                        Self::MAX,
+                    )),
+                    Err(_) => Err(TryFromIntError::new(
+                        i128::MAX,
+                        stringify!($name),
+                        Self::MIN,
+                        Self::MAX,
+                    )),
+                }
+            }
+
+            /// Creates a new variable from any signed or narrower unsigned built in integer,
+            /// clamping it to the range of this type.
+            pub fn saturating_from<T: Into<i128>>(value: T) -> Self {
+                Self::saturate(value.into())
+            }
+
+            /// Creates a new variable from any unsigned built in integer, clamping it to
+            /// the range of this type.
+            pub fn saturating_from_unsigned<T: Into<u128>>(value: T) -> Self {
+                i128::try_from(value.into()).map_or(Self::MAX, Self::saturate)
+            }
+
+            /// Converts `self` into any other integer type of this crate, clamping the
+            /// value to the range of the destination type.
+            pub fn saturating_cast<T: UxInteger>(self) -> T {
+                T::saturating_from_i128(self.as_i128())
+            }
+
+            /// Creates a value bit by bit, setting bit `i` if `f(i)` returns `true`.
+            ///
+            /// `f` is called once for every bit index in `0..Self::BITS`, in order. For
+            /// signed types the bit `Self::BITS - 1` is the sign bit.
+            pub fn from_fn<F: FnMut(u32) -> bool>(mut f: F) -> Self {
+                let mut bits: $type = 0;
+                for i in 0..Self::BITS {
+                    if f(i) {
+                        bits |= 1 << i;
+                    }
+                }
+                $name(bits).mask()
+            }
+
+            /// Creates a value from its representation as a big endian byte slice of
+            /// `Self::BITS` rounded up to whole bytes.
+            ///
+            /// Returns an error instead of discarding bits if the bytes hold a value out of
+            /// range for this type; for signed types the bytes are read as a two's
+            /// complement number.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if `bytes` does not have the right length.
+            pub fn try_from_be_bytes(bytes: &[u8]) -> Result<Self, TryFromIntError> {
+                Self::try_from_bytes(bytes.iter())
+            }
+
+            /// Creates a value from its representation as a little endian byte slice of
+            /// `Self::BITS` rounded up to whole bytes.
+            ///
+            /// Returns an error instead of discarding bits if the bytes hold a value out of
+            /// range for this type; for signed types the bytes are read as a two's
+            /// complement number.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if `bytes` does not have the right length.
+            pub fn try_from_le_bytes(bytes: &[u8]) -> Result<Self, TryFromIntError> {
+                Self::try_from_bytes(bytes.iter().rev())
+            }
+
+            /// Decodes bytes given most significant first.
+            fn try_from_bytes<'a, I: ExactSizeIterator<Item = &'a u8>>(
+                bytes: I,
+            ) -> Result<Self, TryFromIntError> {
+                let len = Self::BITS.div_ceil(8);
+                assert_eq!(bytes.len(), len as usize, "byte slice has the wrong length");
+
+                let raw = bytes.fold(0u128, |raw, &byte| (raw << 8) | u128::from(byte));
+                let value = if Self::MIN.as_i128() < 0 {
+                    // Sign extend from the most significant bit that was read.
+                    let unused = 128 - 8 * len;
+                    Ok(((raw << unused) as i128) >> unused)
+                } else {
+                    i128::try_from(raw)
+                };
+
+                match value {
+                    Ok(value) if value >= Self::MIN.as_i128() && value <= Self::MAX.as_i128() => {
+                        Ok($name(value as $type))
+                    }
+                    value => Err(TryFromIntError::new(
+                        value.unwrap_or(i128::MAX),
+                        stringify!($name),
+                        Self::MIN,
+                        Self::MAX,
+                    )),
+                }
+            }
+
             /// Wrapping (modular) subtraction. Computes `self - other`,
             /// wrapping around at the boundary of the type.
             ///
@@ -155,348 +599,1471 @@ macro_rules! implement_common {
             pub fn wrapping_add(self, rhs: Self) -> Self {
                 $name(self.0.wrapping_add(rhs.0)).mask()
             }
-        }
 
-        impl PartialEq for $name {
-            fn eq(&self, other: &Self) -> bool {
-                self.mask().0 == other.mask().0
+            /// Restricts `self` to the interval `min..=max` in place.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if `min > max`.
+            pub fn clamp_assign(&mut self, min: Self, max: Self) {
+                assert!(min <= max, "assertion failed: min <= max");
+                *self = self.mask();
+                if *self < min {
+                    *self = min.mask();
+                } else if *self > max {
+                    *self = max.mask();
+                }
             }
-        }
-
-        impl Eq for $name {}
 
-        impl PartialOrd for $name {
-            fn partial_cmp(&self, other: &$name) -> Option<Ordering> {
-                self.mask().0.partial_cmp(&other.mask().0)
+            /// Converts the value of `self` into `E` through its `TryFrom` implementation for
+            /// the backing type, such as an enum with a discriminant for each valid value.
+            pub fn to_enum<E: TryFrom<$type>>(self) -> Result<E, E::Error> {
+                E::try_from(self.mask().0)
             }
-        }
 
-        impl Ord for $name {
-            fn cmp(&self, other: &$name) -> Ordering {
-                self.mask().0.cmp(&other.mask().0)
+            /// Compares the value of `self` with `other`.
+            ///
+            /// Unlike converting `other` to this type first, this works for any `other`,
+            /// also when it is out of range for this type.
+            pub fn cmp_primitive(self, other: i128) -> Ordering {
+                self.as_i128().cmp(&other)
             }
-        }
 
-        impl Hash for $name {
-            fn hash<H: Hasher>(&self, h: &mut H) {
-                self.mask().0.hash(h)
+            // The checked and overflowing addition, subtraction and multiplication mask
+            // the operands, apply the overflowing operation of the backing type and report
+            // an overflow if the backing type overflowed or masking changes the result.
+            // Nothing is staged in a wider type. Per width class:
+            //
+            // - 1-7, 9-15, 17-31 and 33-63 bits, backed by the 8, 16, 32 and 64-bit types:
+            //   the backing type has at least one spare bit, so addition and subtraction
+            //   never overflow it and the mask check alone decides. Multiplication
+            //   overflows it above half its width, which its overflow flag reports.
+            //   Staging in `i128` would instead turn every operation into a multi-word
+            //   one on targets without native 128-bit arithmetic.
+            // - 65-127 bits, backed by the 128-bit types: there is no wider primitive, so
+            //   the overflowing operations of the backing type are the only option.
+            //
+            // benches/checked_arithmetic.rs measures this against staging in `i128`.
+
+            /// Checked integer addition. Computes `self + rhs`,
+            /// returning `None` if overflow occurred.
+            pub fn checked_add(self, rhs: Self) -> Option<Self> {
+                match self.overflowing_add(rhs) {
+                    (sum, false) => Some(sum),
+                    (_, true) => None,
+                }
             }
-        }
 
-        // Implement formating functions
-        impl Display for $name {
-            fn fmt(&self, f: &mut Formatter) -> Result<(), lib::core::fmt::Error> {
-                let $name(ref value) = self.mask();
-                <$type as Display>::fmt(value, f)
-            }
-        }
-        impl UpperHex for $name {
-            fn fmt(&self, f: &mut Formatter) -> Result<(), lib::core::fmt::Error> {
-                let $name(ref value) = self.mask();
-                <$type as UpperHex>::fmt(value, f)
+            /// Calculates `self + rhs`.
+            ///
+            /// Returns a tuple of the addition along with a boolean indicating whether
+            /// an arithmetic overflow would occur. If an overflow would have occurred then the
+            /// wrapped value is returned.
+            pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                // The operands are masked first so that bits above the width of this type
+                // do not take part in the overflow check. No wider type is needed: the
+                // backing type always has at least one bit more than this type, so it
+                // only overflows when the sum is out of range too, and masking the
+                // wrapped sum gives the wrapped result either way.
+                let (sum, overflow) = self.mask().0.overflowing_add(rhs.mask().0);
+                let wrapped = $name(sum).mask();
+                (wrapped, overflow || wrapped.0 != sum)
             }
-        }
-        impl LowerHex for $name {
-            fn fmt(&self, f: &mut Formatter) -> Result<(), lib::core::fmt::Error> {
-                let $name(ref value) = self.mask();
-                <$type as LowerHex>::fmt(value, f)
+
+            /// Adds `delta` to `self` in place, wrapping around at the boundary of the type.
+            ///
+            /// Returns `true` if the addition wrapped, such as when a counter rolls over.
+            pub fn wrapping_accumulate(&mut self, delta: Self) -> bool {
+                let (sum, wrapped) = self.overflowing_add(delta);
+                *self = sum;
+                wrapped
             }
-        }
-        impl Octal for $name {
-            fn fmt(&self, f: &mut Formatter) -> Result<(), lib::core::fmt::Error> {
-                let $name(ref value) = self.mask();
-                <$type as Octal>::fmt(value, f)
+
+            /// Calculates `self - rhs`.
+            ///
+            /// Returns a tuple of the subtraction along with a boolean indicating whether
+            /// an arithmetic overflow would occur. If an overflow would have occurred then the
+            /// wrapped value is returned.
+            pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                let (difference, overflow) = self.mask().0.overflowing_sub(rhs.mask().0);
+                let wrapped = $name(difference).mask();
+                (wrapped, overflow || wrapped.0 != difference)
             }
-        }
-        impl Binary for $name {
-            fn fmt(&self, f: &mut Formatter) -> Result<(), lib::core::fmt::Error> {
-                let $name(ref value) = self.mask();
-                <$type as Binary>::fmt(value, f)
+
+            /// Checked integer subtraction. Computes `self - rhs`,
+            /// returning `None` if overflow occurred.
+            pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                match self.overflowing_sub(rhs) {
+                    (difference, false) => Some(difference),
+                    (_, true) => None,
+                }
             }
-        }
 
-        impl<T> Shr<T> for $name
-        where
-            $type: Shr<T, Output = $type>,
-        {
-            type Output = $name;
+            /// Saturating integer addition. Computes `self + rhs`,
+            /// saturating at the numeric bounds instead of overflowing.
+            #[allow(unused_comparisons)]
+            pub fn saturating_add(self, rhs: Self) -> Self {
+                match self.overflowing_add(rhs) {
+                    (sum, false) => sum,
+                    (_, true) if rhs.mask().0 < 0 => Self::MIN,
+                    (_, true) => Self::MAX,
+                }
+            }
 
-            fn shr(self, rhs: T) -> $name {
-                $name(self.mask().0.shr(rhs))
+            /// Saturating integer subtraction. Computes `self - rhs`,
+            /// saturating at the numeric bounds instead of overflowing.
+            #[allow(unused_comparisons)]
+            pub fn saturating_sub(self, rhs: Self) -> Self {
+                match self.overflowing_sub(rhs) {
+                    (difference, false) => difference,
+                    (_, true) if rhs.mask().0 < 0 => Self::MAX,
+                    (_, true) => Self::MIN,
+                }
             }
-        }
 
-        impl<T> Shl<T> for $name
-        where
-            $type: Shl<T, Output = $type>,
-        {
-            type Output = $name;
+            /// Sums the values of an iterator, stopping at the first overflow.
+            ///
+            /// Returns the sum, or the index of the value whose addition overflowed.
+            pub fn try_sum_indexed<I: IntoIterator<Item = Self>>(iter: I) -> Result<Self, usize> {
+                iter.into_iter()
+                    .enumerate()
+                    .try_fold($name(0), |sum, (index, value)| {
+                        sum.checked_add(value).ok_or(index)
+                    })
+            }
 
-            fn shl(self, rhs: T) -> $name {
-                $name(self.mask().0.shl(rhs))
+            /// Wrapping (modular) addition of two slices. Computes `dst[i] = dst[i] + src[i]`
+            /// for every element, wrapping around at the boundary of the type.
+            ///
+            /// The backing values are added in one tight loop and the results are masked
+            /// once in a second pass, so that both loops can be auto-vectorized. The bits
+            /// within the width of a wrapping sum only depend on the bits within the width
+            /// of the operands, so masking at the end gives the same result as masking
+            /// every element.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if the two slices have different lengths.
+            pub fn wrapping_add_slice(dst: &mut [Self], src: &[Self]) {
+                assert_eq!(dst.len(), src.len(), "slices must have the same length");
+                for (d, s) in dst.iter_mut().zip(src) {
+                    d.0 = d.0.wrapping_add(s.0);
+                }
+                for d in dst.iter_mut() {
+                    *d = d.mask();
+                }
             }
-        }
 
-        impl<T> ShrAssign<T> for $name
-        where
-            $type: ShrAssign<T>,
-        {
-            fn shr_assign(&mut self, rhs: T) {
-                *self = self.mask();
-                self.0.shr_assign(rhs);
+            /// Saturating addition of a wider signed value. Computes `self + rhs`,
+            /// saturating at the numeric bounds instead of overflowing.
+            pub fn saturating_add_wide(self, rhs: i64) -> Self {
+                match self.as_i128().checked_add(i128::from(rhs)) {
+                    Some(sum) => Self::saturate(sum),
+                    None if rhs > 0 => Self::MAX,
+                    None => Self::MIN,
+                }
             }
-        }
 
-        impl<T> ShlAssign<T> for $name
-        where
-            $type: ShlAssign<T>,
-        {
-            fn shl_assign(&mut self, rhs: T) {
-                *self = self.mask();
-                self.0.shl_assign(rhs);
+            /// Saturating addition of a wider unsigned value. Computes `self + rhs`,
+            /// saturating at the numeric bounds instead of overflowing.
+            pub fn saturating_add_wide_unsigned(self, rhs: u64) -> Self {
+                match self.as_i128().checked_add(i128::from(rhs)) {
+                    Some(sum) => Self::saturate(sum),
+                    None => Self::MAX,
+                }
             }
-        }
 
-        impl BitOr<$name> for $name {
-            type Output = $name;
+            /// Clamps `value` to the range of this type.
+            fn saturate(value: i128) -> Self {
+                if value > Self::MAX.as_i128() {
+                    Self::MAX
+                } else if value < Self::MIN.as_i128() {
+                    Self::MIN
+                } else {
+                    $name(value as $type)
+                }
+            }
 
-            fn bitor(self, rhs: $name) -> Self::Output {
-                $name(self.mask().0.bitor(rhs.mask().0))
+            /// Checked integer multiplication. Computes `self * rhs`,
+            /// returning `None` if overflow occurred.
+            pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+                match self.overflowing_mul(rhs) {
+                    (product, false) => Some(product),
+                    (_, true) => None,
+                }
             }
-        }
 
-        impl<'a> BitOr<&'a $name> for $name {
-            type Output = <$name as BitOr<$name>>::Output;
+            /// Calculates the multiplication of `self` and `rhs`.
+            ///
+            /// Returns a tuple of the multiplication along with a boolean indicating whether
+            /// an arithmetic overflow would occur. If an overflow would have occurred then the
+            /// wrapped value is returned.
+            pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                // The product wraps modulo the backing type, whose width is a multiple of
+                // the wrapping width of this type, so masking gives the wrapped result even
+                // when the backing type overflows. If it did not, the product is in range
+                // exactly when masking leaves it unchanged.
+                let (product, overflow) = self.mask().0.overflowing_mul(rhs.mask().0);
+                let wrapped = $name(product).mask();
+                (wrapped, overflow || wrapped.0 != product)
+            }
 
-            fn bitor(self, rhs: &'a $name) -> Self::Output {
-                $name(self.mask().0.bitor(rhs.mask().0))
+            /// Wrapping (modular) multiplication. Computes `self * rhs`,
+            /// wrapping around at the boundary of the type.
+            pub fn wrapping_mul(self, rhs: Self) -> Self {
+                $name(self.0.wrapping_mul(rhs.0)).mask()
             }
-        }
 
-        impl<'a> BitOr<$name> for &'a $name {
-            type Output = <$name as BitOr<$name>>::Output;
+            /// Saturating integer multiplication. Computes `self * rhs`,
+            /// saturating at the numeric bounds instead of overflowing.
+            #[allow(unused_comparisons)]
+            pub fn saturating_mul(self, rhs: Self) -> Self {
+                match self.overflowing_mul(rhs) {
+                    (product, false) => product,
+                    (_, true) if (self.mask().0 < 0) != (rhs.mask().0 < 0) => Self::MIN,
+                    (_, true) => Self::MAX,
+                }
+            }
 
-            fn bitor(self, rhs: $name) -> Self::Output {
-                $name(self.mask().0.bitor(rhs.mask().0))
+            /// Checked exponentiation. Computes `self.pow(exp)`, returning `None` if
+            /// overflow occurred.
+            pub fn checked_pow(self, mut exp: u32) -> Option<Self> {
+                if exp == 0 {
+                    return Self::new_from(1).ok();
+                }
+                let mut base = self.mask();
+                let mut acc: Option<Self> = None;
+                while exp > 1 {
+                    if exp & 1 == 1 {
+                        acc = Some(match acc {
+                            Some(acc) => acc.checked_mul(base)?,
+                            None => base,
+                        });
+                    }
+                    exp /= 2;
+                    base = base.checked_mul(base)?;
+                }
+                match acc {
+                    Some(acc) => acc.checked_mul(base),
+                    None => Some(base),
+                }
             }
-        }
 
-        impl<'a> BitOr<&'a $name> for &'a $name {
-            type Output = <$name as BitOr<$name>>::Output;
+            /// Saturating exponentiation. Computes `self.pow(exp)`,
+            /// saturating at the numeric bounds instead of overflowing.
+            #[allow(unused_comparisons)]
+            pub fn saturating_pow(self, exp: u32) -> Self {
+                match self.checked_pow(exp) {
+                    Some(power) => power,
+                    None if self.mask().0 < 0 && exp % 2 == 1 => Self::MIN,
+                    None => Self::MAX,
+                }
+            }
 
-            fn bitor(self, rhs: &'a $name) -> Self::Output {
-                $name(self.mask().0.bitor(rhs.mask().0))
+            /// Raises `self` to the power of `exp`, using exponentiation by squaring.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic in debug mode if the result is out of range for
+            /// this type. In release mode the result wraps.
+            pub fn pow(self, exp: u32) -> Self {
+                let (power, overflow) = self.overflowing_pow(exp);
+                debug_assert!(!overflow, "attempt to multiply with overflow");
+                power
             }
-        }
 
-        impl BitOrAssign<$name> for $name {
-            fn bitor_assign(&mut self, other: $name) {
-                *self = self.mask();
-                self.0.bitor_assign(other.mask().0)
+            /// Wrapping (modular) exponentiation. Computes `self.pow(exp)`, wrapping around
+            /// at the boundary of the type.
+            pub fn wrapping_pow(self, mut exp: u32) -> Self {
+                let mut base = self.mask();
+                let mut acc = $name(1).mask();
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        acc = acc.wrapping_mul(base);
+                    }
+                    exp /= 2;
+                    if exp > 0 {
+                        base = base.wrapping_mul(base);
+                    }
+                }
+                acc
             }
-        }
 
-        impl BitXor<$name> for $name {
-            type Output = $name;
+            /// Raises `self` to the power of `exp`, using exponentiation by squaring.
+            ///
+            /// Returns a tuple of the exponentiation along with a boolean indicating whether
+            /// an overflow happened. If an overflow would have occurred then the wrapped
+            /// value is returned.
+            pub fn overflowing_pow(self, exp: u32) -> (Self, bool) {
+                (self.wrapping_pow(exp), self.checked_pow(exp).is_none())
+            }
 
-            fn bitxor(self, rhs: $name) -> Self::Output {
-                $name(self.mask().0.bitxor(rhs.mask().0))
+            /// Checked multiplication by a value of the backing type. Computes `self * rhs`,
+            /// returning `None` if the product is out of range for this type.
+            pub fn checked_mul_primitive(self, rhs: $type) -> Option<Self> {
+                let (product, overflow) = self.mask().0.overflowing_mul(rhs);
+                let wrapped = $name(product).mask();
+                if overflow || wrapped.0 != product {
+                    None
+                } else {
+                    Some(wrapped)
+                }
             }
-        }
 
-        impl<'a> BitXor<&'a $name> for $name {
-            type Output = <$name as BitOr<$name>>::Output;
+            /// Wrapping (modular) multiplication by a value of the backing type. Computes
+            /// `self * rhs`, wrapping around at the boundary of this type.
+            pub fn wrapping_mul_primitive(self, rhs: $type) -> Self {
+                $name(self.mask().0.wrapping_mul(rhs)).mask()
+            }
 
-            fn bitxor(self, rhs: &'a $name) -> Self::Output {
-                $name(self.mask().0.bitxor(rhs.mask().0))
+            /// Calculates `self / rhs`.
+            ///
+            /// Returns a tuple of the quotient along with a boolean indicating whether
+            /// an arithmetic overflow would occur, which only happens for `Self::MIN / -1`
+            /// on signed types. If an overflow would have occurred then `self` is returned.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if `rhs` is zero.
+            pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+                let quotient = self.mask().0 / rhs.mask().0;
+                let wrapped = $name(quotient).mask();
+                (wrapped, wrapped.0 != quotient)
             }
-        }
 
-        impl<'a> BitXor<$name> for &'a $name {
-            type Output = <$name as BitOr<$name>>::Output;
+            /// Negates `self`, wrapping around at the boundary of the type.
+            ///
+            /// Returns a tuple of the negated value along with a boolean indicating whether
+            /// an overflow happened: for unsigned types whenever `self` is not zero, and
+            /// for signed types if `self == Self::MIN`.
+            pub fn overflowing_neg(self) -> (Self, bool) {
+                let (negated, overflow) = self.mask().0.overflowing_neg();
+                let wrapped = $name(negated).mask();
+                (wrapped, overflow || wrapped.0 != negated)
+            }
 
-            fn bitxor(self, rhs: $name) -> Self::Output {
-                $name(self.mask().0.bitxor(rhs.mask().0))
+            /// Wrapping (modular) negation. Computes `-self`, wrapping around at the
+            /// boundary of the type.
+            ///
+            /// For unsigned types this returns `Self::MAX - self + 1` for every value but
+            /// zero, and for signed types `Self::MIN` is returned unchanged.
+            pub fn wrapping_neg(self) -> Self {
+                $name(self.0.wrapping_neg()).mask()
             }
-        }
 
-        impl<'a> BitXor<&'a $name> for &'a $name {
-            type Output = <$name as BitOr<$name>>::Output;
+            /// Checked integer division. Computes `self / rhs`,
+            /// returning `None` if `rhs == 0` or the division results in overflow.
+            pub fn checked_div(self, rhs: Self) -> Option<Self> {
+                let quotient = self.mask().0.checked_div(rhs.mask().0)?;
+                let wrapped = $name(quotient).mask();
+                if wrapped.0 == quotient {
+                    Some(wrapped)
+                } else {
+                    None
+                }
+            }
 
-            fn bitxor(self, rhs: &'a $name) -> Self::Output {
-                $name(self.mask().0.bitxor(rhs.mask().0))
+            /// Checked integer remainder. Computes `self % rhs`,
+            /// returning `None` if `rhs == 0` or the division results in overflow.
+            pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+                self.checked_div(rhs)?;
+                Some($name(self.mask().0 % rhs.mask().0))
             }
-        }
 
-        impl BitXorAssign<$name> for $name {
-            fn bitxor_assign(&mut self, other: $name) {
-                *self = self.mask();
-                self.0.bitxor_assign(other.mask().0)
+            /// Wrapping (modular) division. Computes `self / rhs`, wrapping around at the
+            /// boundary of the type.
+            ///
+            /// The only case where such wrapping can occur is `Self::MIN / -1` on signed
+            /// types, which returns `Self::MIN`.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if `rhs` is zero.
+            pub fn wrapping_div(self, rhs: Self) -> Self {
+                $name(self.mask().0 / rhs.mask().0).mask()
             }
-        }
 
-        impl Not for $name {
-            type Output = $name;
+            /// Wrapping (modular) remainder. Computes `self % rhs`, wrapping around at the
+            /// boundary of the type.
+            ///
+            /// `Self::MIN % -1` on signed types returns `0`.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if `rhs` is zero.
+            pub fn wrapping_rem(self, rhs: Self) -> Self {
+                $name(self.mask().0 % rhs.mask().0).mask()
+            }
 
-            fn not(self) -> $name {
-                $name(self.mask().0.not())
+            /// Returns the logarithm of the number with respect to an arbitrary base,
+            /// rounded down.
+            ///
+            /// Returns `None` if the number is negative or zero, or if the base is not at
+            /// least 2.
+            pub fn checked_ilog(self, base: Self) -> Option<u32> {
+                self.mask().0.checked_ilog(base.mask().0)
             }
-        }
 
-        impl<'a> Not for &'a $name {
-            type Output = <$name as Not>::Output;
+            /// Returns the base 2 logarithm of the number, rounded down.
+            ///
+            /// Returns `None` if the number is negative or zero.
+            pub fn checked_ilog2(self) -> Option<u32> {
+                self.mask().0.checked_ilog2()
+            }
 
-            fn not(self) -> $name {
-                $name(self.mask().0.not())
+            /// Returns the logarithm of the number with respect to an arbitrary base,
+            /// rounded down.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if `self` is less than or equal to zero,
+            /// or if `base` is less than 2.
+            pub fn ilog(self, base: Self) -> u32 {
+                assert!(
+                    base.mask().0 >= 2,
+                    "base of integer logarithm must be at least 2"
+                );
+                match self.checked_ilog(base) {
+                    Some(log) => log,
+                    None => panic!("argument of integer logarithm must be positive"),
+                }
             }
-        }
 
-        impl BitAnd<$name> for $name {
-            type Output = $name;
+            /// Returns the base 2 logarithm of the number, rounded down.
+            ///
+            /// The result is always less than `Self::BITS`.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if `self` is less than or equal to zero.
+            pub fn ilog2(self) -> u32 {
+                match self.checked_ilog2() {
+                    Some(log) => log,
+                    None => panic!("argument of integer logarithm must be positive"),
+                }
+            }
 
-            fn bitand(self, rhs: $name) -> Self::Output {
-                $name(self.mask().0.bitand(rhs.mask().0))
+            /// Parses an integer from a string slice with digits in the given base.
+            ///
+            /// The string is expected to be an optional `+` sign, or `-` sign for signed
+            /// types, followed by digits. Like in Rust literals the digits may be separated
+            /// by single underscores, eg. `"1_000"`, but the string can not begin or end
+            /// with an underscore.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if `radix` is not in the range `2..=36`.
+            pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+                let value =
+                    parse::parse_i128(src, radix, Self::MIN.as_i128(), Self::MAX.as_i128())?;
+                Ok($name(value as $type))
             }
-        }
 
-        impl<'a> BitAnd<&'a $name> for $name {
-            type Output = <$name as BitOr<$name>>::Output;
+            /// Writes the digits of the absolute value of `self` in the given radix into
+            /// `buf`, most significant digit first, and returns the written part of `buf`.
+            ///
+            /// The digits are the numeric digit values, not characters. Zero is written
+            /// as a single `0` digit. A buffer of `Self::BITS` bytes is always large
+            /// enough.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if `radix` is not in the range `2..=256`,
+            /// or if `buf` is too small to hold all the digits.
+            pub fn to_digits(self, radix: u32, buf: &mut [u8]) -> &[u8] {
+                assert!(
+                    (2..=256).contains(&radix),
+                    "radix must be in the range 2..=256"
+                );
+                let radix = u128::from(radix);
+                let mut magnitude = self.as_i128().unsigned_abs();
+                let mut start = buf.len();
+                loop {
+                    assert!(start > 0, "buffer too small to hold all digits");
+                    start -= 1;
+                    buf[start] = (magnitude % radix) as u8;
+                    magnitude /= radix;
+                    if magnitude == 0 {
+                        break;
+                    }
+                }
+                &buf[start..]
+            }
 
-            fn bitand(self, rhs: &'a $name) -> Self::Output {
-                $name(self.mask().0.bitand(rhs.mask().0))
+            /// Returns the string representation of `self` in the given radix, using
+            /// lowercase letters for digits above 9 and a leading `-` for negative values.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if `radix` is not in the range `2..=36`.
+            #[cfg(feature = "std")]
+            pub fn to_string_radix(self, radix: u32) -> String {
+                assert!(
+                    (2..=36).contains(&radix),
+                    "radix must be in the range 2..=36"
+                );
+                let mut buf = [0u8; 128];
+                let digits = self.to_digits(radix, &mut buf);
+                let mut s = String::with_capacity(digits.len() + 1);
+                if self.mask().0 < (0 as $type) {
+                    s.push('-');
+                }
+                s.extend(
+                    digits
+                        .iter()
+                        .map(|&d| char::from_digit(d.into(), radix).unwrap()),
+                );
+                s
             }
-        }
 
-        impl<'a> BitAnd<$name> for &'a $name {
-            type Output = <$name as BitOr<$name>>::Output;
+            /// Shifts the bits to the left by `n`, wrapping the truncated bits to the end
+            /// of the resulting integer.
+            ///
+            /// The rotation happens within the `Self::BITS` bits of this type. Unlike the
+            /// built in types, which reduce `n` modulo the width of the primitive, `n` is
+            /// reduced modulo `Self::BITS`, so rotating by `Self::BITS` returns `self`.
+            #[allow(clippy::modulo_one)]
+            pub fn rotate_left(self, n: u32) -> Self {
+                let n = n % Self::BITS;
+                let bits = self.mask().0 & Self::MASK;
+                $name((bits << n) | (bits >> (Self::BITS - n))).mask()
+            }
 
-            fn bitand(self, rhs: $name) -> Self::Output {
-                $name(self.mask().0.bitand(rhs.mask().0))
+            /// Shifts the bits to the right by `n`, wrapping the truncated bits to the
+            /// beginning of the resulting integer.
+            ///
+            /// The rotation happens within the `Self::BITS` bits of this type. Unlike the
+            /// built in types, which reduce `n` modulo the width of the primitive, `n` is
+            /// reduced modulo `Self::BITS`, so rotating by `Self::BITS` returns `self`.
+            #[allow(clippy::modulo_one)]
+            pub fn rotate_right(self, n: u32) -> Self {
+                self.rotate_left(Self::BITS - n % Self::BITS)
             }
-        }
 
-        impl<'a> BitAnd<&'a $name> for &'a $name {
-            type Output = <$name as BitOr<$name>>::Output;
+            /// Reverses the order of the `Self::BITS` bits of this type; the least
+            /// significant bit becomes the most significant bit.
+            pub fn reverse_bits(self) -> Self {
+                $name(self.mask().0.reverse_bits() >> ($type::BITS - Self::BITS)).mask()
+            }
 
-            fn bitand(self, rhs: &'a $name) -> Self::Output {
-                $name(self.mask().0.bitand(rhs.mask().0))
+            /// Returns the minimum number of bits needed to represent `self`, zero for zero.
+            ///
+            /// For unsigned types this is `Self::BITS` minus the number of leading zeros.
+            /// For signed types the sign bit is not counted: the result is the bit width of
+            /// `self` for non-negative values and of `!self` for negative values, so a
+            /// signed value fits in `self.bit_width() + 1` bits.
+            #[allow(unused_comparisons)]
+            pub fn bit_width(self) -> u32 {
+                let bits = self.mask().0;
+                let magnitude = if bits < 0 { !bits } else { bits };
+                $type::BITS - magnitude.leading_zeros()
             }
-        }
 
-        impl BitAndAssign<$name> for $name {
-            fn bitand_assign(&mut self, other: $name) {
-                *self = self.mask();
-                self.0.bitand_assign(other.mask().0)
+            /// Returns an iterator over the indices of the set bits among the `Self::BITS`
+            /// bits of this type, least significant bit first.
+            pub fn set_bits(self) -> impl Iterator<Item = u32> {
+                let bits = self.mask().0;
+                (0..Self::BITS).filter(move |&i| (bits >> i) & 1 == 1)
             }
-        }
 
-        impl lib::core::ops::Add<$name> for $name {
-            type Output = $name;
-            #[allow(unused_comparisons)]
-            fn add(self, other: $name) -> $name {
-                if self.0 > 0 && other.0 > 0 {
-                    debug_assert!(Self::MAX.0 - other.0 >= self.0);
-                } else if self.0 < 0 && other.0 < 0 {
-                    debug_assert!(Self::MIN.0 - other.0 <= self.0);
-                }
-                self.wrapping_add(other)
+            /// Returns the number of ones among the `Self::BITS` bits of this type.
+            pub fn count_ones(self) -> u32 {
+                (self.mask().0 & Self::MASK).count_ones()
             }
-        }
 
-        impl lib::core::ops::Sub<$name> for $name {
-            type Output = $name;
-            #[allow(unused_comparisons)]
-            fn sub(self, other: $name) -> $name {
-                if self > other {
-                    debug_assert!(Self::MAX.0 + other.0 >= self.0);
-                } else if self < other {
-                    debug_assert!(Self::MIN.0 + other.0 <= self.0);
-                }
-                self.wrapping_sub(other)
+            /// Returns the number of zeros among the `Self::BITS` bits of this type.
+            ///
+            /// Sign extended bits of the underlying container are not counted.
+            pub fn count_zeros(self) -> u32 {
+                Self::BITS - self.count_ones()
             }
-        }
-    };
-}
 
-define_unsigned!(#[doc="The 1-bit unsigned integer type."], u1, 1, u8);
-define_unsigned!(#[doc="The 2-bit unsigned integer type."], u2, 2, u8);
-define_unsigned!(#[doc="The 3-bit unsigned integer type."], u3, 3, u8);
-define_unsigned!(#[doc="The 4-bit unsigned integer type."], u4, 4, u8);
-define_unsigned!(#[doc="The 5-bit unsigned integer type."], u5, 5, u8);
-define_unsigned!(#[doc="The 6-bit unsigned integer type."], u6, 6, u8);
-define_unsigned!(#[doc="The 7-bit unsigned integer type."], u7, 7, u8);
+            /// Returns the number of leading zeros among the `Self::BITS` bits of this type.
+            ///
+            /// Negative values have no leading zeros, as their most significant bit is set.
+            pub fn leading_zeros(self) -> u32 {
+                (self.mask().0 & Self::MASK).leading_zeros() - ($type::BITS - Self::BITS)
+            }
 
-define_unsigned!(#[doc="The 9-bit unsigned integer type."], u9, 9, u16);
-define_unsigned!(#[doc="The 10-bit unsigned integer type."], u10, 10, u16);
-define_unsigned!(#[doc="The 11-bit unsigned integer type."], u11, 11, u16);
-define_unsigned!(#[doc="The 12-bit unsigned integer type."], u12, 12, u16);
-define_unsigned!(#[doc="The 13-bit unsigned integer type."], u13, 13, u16);
-define_unsigned!(#[doc="The 14-bit unsigned integer type."], u14, 14, u16);
-define_unsigned!(#[doc="The 15-bit unsigned integer type."], u15, 15, u16);
+            /// Returns the number of trailing zeros among the `Self::BITS` bits of this type.
+            ///
+            /// Zero has `Self::BITS` trailing zeros.
+            pub fn trailing_zeros(self) -> u32 {
+                (self.mask().0 & Self::MASK)
+                    .trailing_zeros()
+                    .min(Self::BITS)
+            }
 
-define_unsigned!(#[doc="The 17-bit unsigned integer type."], u17, 17, u32);
-define_unsigned!(#[doc="The 18-bit unsigned integer type."], u18, 18, u32);
-define_unsigned!(#[doc="The 19-bit unsigned integer type."], u19, 19, u32);
-define_unsigned!(#[doc="The 20-bit unsigned integer type."], u20, 20, u32);
-define_unsigned!(#[doc="The 21-bit unsigned integer type."], u21, 21, u32);
-define_unsigned!(#[doc="The 22-bit unsigned integer type."], u22, 22, u32);
-define_unsigned!(#[doc="The 23-bit unsigned integer type."], u23, 23, u32);
-define_unsigned!(#[doc="The 24-bit unsigned integer type."], u24, 24, u32);
+            /// Returns the number of leading ones among the `Self::BITS` bits of this type.
+            ///
+            /// For negative values the sign bit is the first of the leading ones.
+            pub fn leading_ones(self) -> u32 {
+                (!self.mask().0 & Self::MASK).leading_zeros() - ($type::BITS - Self::BITS)
+            }
 
-define_unsigned!(#[doc="The 25-bit unsigned integer type."], u25, 25, u32);
-define_unsigned!(#[doc="The 26-bit unsigned integer type."], u26, 26, u32);
-define_unsigned!(#[doc="The 27-bit unsigned integer type."], u27, 27, u32);
-define_unsigned!(#[doc="The 28-bit unsigned integer type."], u28, 28, u32);
-define_unsigned!(#[doc="The 29-bit unsigned integer type."], u29, 29, u32);
-define_unsigned!(#[doc="The 30-bit unsigned integer type."], u30, 30, u32);
-define_unsigned!(#[doc="The 31-bit unsigned integer type."], u31, 31, u32);
+            /// Returns the number of trailing ones among the `Self::BITS` bits of this type.
+            pub fn trailing_ones(self) -> u32 {
+                (self.mask().0 & Self::MASK).trailing_ones()
+            }
 
-define_unsigned!(#[doc="The 33-bit unsigned integer type."], u33, 33, u64);
-define_unsigned!(#[doc="The 34-bit unsigned integer type."], u34, 34, u64);
-define_unsigned!(#[doc="The 35-bit unsigned integer type."], u35, 35, u64);
-define_unsigned!(#[doc="The 36-bit unsigned integer type."], u36, 36, u64);
-define_unsigned!(#[doc="The 37-bit unsigned integer type."], u37, 37, u64);
-define_unsigned!(#[doc="The 38-bit unsigned integer type."], u38, 38, u64);
-define_unsigned!(#[doc="The 39-bit unsigned integer type."], u39, 39, u64);
-define_unsigned!(#[doc="The 40-bit unsigned integer type."], u40, 40, u64);
+            /// Reverses the order of the `Self::BITS.div_ceil(8)` bytes holding the bits of
+            /// this type.
+            ///
+            /// The bits of `self` are split into bytes starting from the least significant
+            /// end, so the most significant byte is the partial one holding only
+            /// `Self::BITS % 8` bits when the width is not a multiple of eight. After the
+            /// reversal the former least significant byte is in that partial position and
+            /// only its low `Self::BITS % 8` bits are kept; its high bits are discarded.
+            /// Applying the reversal twice therefore only round-trips when those bits
+            /// are zero.
+            ///
+            /// For `u12`, `0xabc` is split into the bytes `0x0a, 0xbc`, which are reversed
+            /// to `0xbc, 0x0a` and truncated to `0xc0a`.
+            pub fn reverse_bytes(self) -> Self {
+                let len = Self::BITS.div_ceil(8);
+                let bits = self.as_u128() & (u128::MAX >> (128 - Self::BITS));
+                $name((bits.swap_bytes() >> (128 - 8 * len)) as $type).mask()
+            }
 
-define_unsigned!(#[doc="The 41-bit unsigned integer type."], u41, 41, u64);
-define_unsigned!(#[doc="The 42-bit unsigned integer type."], u42, 42, u64);
-define_unsigned!(#[doc="The 43-bit unsigned integer type."], u43, 43, u64);
-define_unsigned!(#[doc="The 44-bit unsigned integer type."], u44, 44, u64);
-define_unsigned!(#[doc="The 45-bit unsigned integer type."], u45, 45, u64);
-define_unsigned!(#[doc="The 46-bit unsigned integer type."], u46, 46, u64);
-define_unsigned!(#[doc="The 47-bit unsigned integer type."], u47, 47, u64);
-define_unsigned!(#[doc="The 48-bit unsigned integer type."], u48, 48, u64);
+            /// Extracts the bits `lo..hi` of `self`.
+            ///
+            /// The extracted bits are moved down to the least significant end of the
+            /// result and all other bits of the result are zero.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if `lo > hi` or `hi > Self::BITS`.
+            pub fn bit_range(self, lo: u32, hi: u32) -> Self {
+                assert!(lo <= hi && hi <= Self::BITS, "bit range out of bounds");
+                let field = ((1 as $type) << (hi - lo)).wrapping_sub(1);
+                $name((self.mask().0 >> lo) & field)
+            }
 
-define_unsigned!(#[doc="The 49-bit unsigned integer type."], u49, 49, u64);
-define_unsigned!(#[doc="The 50-bit unsigned integer type."], u50, 50, u64);
-define_unsigned!(#[doc="The 51-bit unsigned integer type."], u51, 51, u64);
-define_unsigned!(#[doc="The 52-bit unsigned integer type."], u52, 52, u64);
-define_unsigned!(#[doc="The 53-bit unsigned integer type."], u53, 53, u64);
-define_unsigned!(#[doc="The 54-bit unsigned integer type."], u54, 54, u64);
-define_unsigned!(#[doc="The 55-bit unsigned integer type."], u55, 55, u64);
-define_unsigned!(#[doc="The 56-bit unsigned integer type."], u56, 56, u64);
+            /// Returns `true` if `self` is a multiple of `2^alignment`, that is if its low
+            /// `alignment` bits are zero.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if `alignment > Self::BITS`.
+            pub fn is_aligned_to(self, alignment: u32) -> bool {
+                assert!(alignment <= Self::BITS, "alignment out of bounds");
+                self.mask().0 & ((1 as $type) << alignment).wrapping_sub(1) == 0
+            }
 
-define_unsigned!(#[doc="The 57-bit unsigned integer type."], u57, 57, u64);
-define_unsigned!(#[doc="The 58-bit unsigned integer type."], u58, 58, u64);
-define_unsigned!(#[doc="The 59-bit unsigned integer type."], u59, 59, u64);
-define_unsigned!(#[doc="The 60-bit unsigned integer type."], u60, 60, u64);
-define_unsigned!(#[doc="The 61-bit unsigned integer type."], u61, 61, u64);
-define_unsigned!(#[doc="The 62-bit unsigned integer type."], u62, 62, u64);
-define_unsigned!(#[doc="The 63-bit unsigned integer type."], u63, 63, u64);
+            /// Splits `self` into its low `n` bits and its high `Self::BITS - n` bits.
+            ///
+            /// Both parts are moved down to the least significant end and all other bits
+            /// are zero, so `low | high << n` reassembles `self` for unsigned types.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if `n > Self::BITS`.
+            pub fn split_at_bit(self, n: u32) -> (Self, Self) {
+                assert!(n <= Self::BITS, "bit index out of bounds");
+                (self.bit_range(0, n), self.bit_range(n, Self::BITS))
+            }
 
-define_unsigned!(#[doc="The 65-bit unsigned integer type."], u65, 65, u128);
-define_unsigned!(#[doc="The 66-bit unsigned integer type."], u66, 66, u128);
-define_unsigned!(#[doc="The 67-bit unsigned integer type."], u67, 67, u128);
-define_unsigned!(#[doc="The 68-bit unsigned integer type."], u68, 68, u128);
-define_unsigned!(#[doc="The 69-bit unsigned integer type."], u69, 69, u128);
-define_unsigned!(#[doc="The 70-bit unsigned integer type."], u70, 70, u128);
-define_unsigned!(#[doc="The 71-bit unsigned integer type."], u71, 71, u128);
-define_unsigned!(#[doc="The 72-bit unsigned integer type."], u72, 72, u128);
+            /// Replaces the bits `lo..hi` of `self` with the low `hi - lo` bits of `value`.
+            ///
+            /// Bits of `value` that do not fit the range are ignored and the bits of `self`
+            /// outside the range are left intact.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if `lo > hi` or `hi > Self::BITS`.
+            pub fn with_bit_range(self, lo: u32, hi: u32, value: Self) -> Self {
+                assert!(lo <= hi && hi <= Self::BITS, "bit range out of bounds");
+                let field = ((1 as $type) << (hi - lo)).wrapping_sub(1);
+                let cleared = self.mask().0 & !(field << lo);
+                $name(cleared | ((value.mask().0 & field) << lo)).mask()
+            }
 
-define_unsigned!(#[doc="The 73-bit unsigned integer type."], u73, 73, u128);
-define_unsigned!(#[doc="The 74-bit unsigned integer type."], u74, 74, u128);
+            /// Shifts the bits to the left by `n`, discarding the bits shifted past the width.
+            ///
+            /// Unlike the `<<` operator this takes the shift amount as `u32`, like the
+            /// shift methods of the built in types do.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic in debug mode if `n >= Self::BITS`. In release mode
+            /// the shift amount is reduced modulo `Self::BITS`.
+            #[allow(clippy::modulo_one)]
+            pub fn shl_u32(self, n: u32) -> Self {
+                debug_assert!(n < Self::BITS, "attempt to shift left with overflow");
+                $name(self.mask().0 << (n % Self::BITS)).mask()
+            }
+
+            /// Saturating shift left. Computes `self << n`, returning `Self::MAX` (or
+            /// `Self::MIN` for negative values) instead of shifting any significant bit
+            /// out of the width.
+            ///
+            /// For signed types the sign bit is significant, so the result always has
+            /// the sign of `self`.
+            #[allow(unused_comparisons)]
+            pub fn saturating_shl(self, n: u32) -> Self {
+                let value = self.mask();
+                if n < Self::BITS {
+                    let shifted = $name(value.0 << n).mask();
+                    if shifted.0 >> n == value.0 {
+                        return shifted;
+                    }
+                } else if value.0 == 0 {
+                    return value;
+                }
+
+                if value.0 < 0 {
+                    Self::MIN
+                } else {
+                    Self::MAX
+                }
+            }
+
+            /// Shifts the bits to the right by `n`. Signed types shift in copies of the sign bit.
+            ///
+            /// Unlike the `>>` operator this takes the shift amount as `u32`, like the
+            /// shift methods of the built in types do.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic in debug mode if `n >= Self::BITS`. In release mode
+            /// the shift amount is reduced modulo `Self::BITS`.
+            #[allow(clippy::modulo_one)]
+            pub fn shr_u32(self, n: u32) -> Self {
+                debug_assert!(n < Self::BITS, "attempt to shift right with overflow");
+                $name(self.mask().0 >> (n % Self::BITS)).mask()
+            }
+
+            /// Panic-free bitwise shift-left. Computes `self << n`, where `n` is first
+            /// reduced modulo `Self::BITS`, like `u8::wrapping_shl` reduces it modulo 8.
+            #[allow(clippy::modulo_one)]
+            pub fn wrapping_shl(self, n: u32) -> Self {
+                $name(self.mask().0 << (n % Self::BITS)).mask()
+            }
+
+            /// Panic-free bitwise shift-right. Computes `self >> n`, where `n` is first
+            /// reduced modulo `Self::BITS`. Signed types shift in copies of the sign bit.
+            #[allow(clippy::modulo_one)]
+            pub fn wrapping_shr(self, n: u32) -> Self {
+                $name(self.mask().0 >> (n % Self::BITS)).mask()
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.mask().0 == other.mask().0
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &$name) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &$name) -> Ordering {
+                // Values with bits set above the width can still be built inside the crate,
+                // so the container values are masked rather than compared directly.
+                self.mask().0.cmp(&other.mask().0)
+            }
+        }
+
+        impl UxInteger for $name {
+            fn width(&self) -> u32 {
+                $bits
+            }
+
+            fn as_u128(&self) -> u128 {
+                self.mask().0 as u128
+            }
+
+            fn as_i128(&self) -> i128 {
+                self.mask().0 as i128
+            }
+
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                $name::checked_div(self, rhs)
+            }
+
+            fn checked_rem(self, rhs: Self) -> Option<Self> {
+                $name::checked_rem(self, rhs)
+            }
+
+            fn saturating_from_i128(value: i128) -> Self {
+                Self::saturate(value)
+            }
+        }
+
+        impl conversion::Wide for $name {
+            fn wide(self) -> i128 {
+                self.mask().0 as i128
+            }
+        }
+
+        impl Hash for $name {
+            fn hash<H: Hasher>(&self, h: &mut H) {
+                self.mask().0.hash(h)
+            }
+        }
+
+        // Implement formating functions
+        impl lib::core::str::FromStr for $name {
+            type Err = ParseIntError;
+
+            fn from_str(src: &str) -> Result<Self, Self::Err> {
+                Self::from_str_radix(src, 10)
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter) -> Result<(), lib::core::fmt::Error> {
+                let $name(ref value) = self.mask();
+                <$type as Display>::fmt(value, f)
+            }
+        }
+        impl UpperHex for $name {
+            fn fmt(&self, f: &mut Formatter) -> Result<(), lib::core::fmt::Error> {
+                let $name(ref value) = self.mask();
+                <$type as UpperHex>::fmt(value, f)
+            }
+        }
+        impl LowerHex for $name {
+            fn fmt(&self, f: &mut Formatter) -> Result<(), lib::core::fmt::Error> {
+                let $name(ref value) = self.mask();
+                <$type as LowerHex>::fmt(value, f)
+            }
+        }
+        impl Octal for $name {
+            fn fmt(&self, f: &mut Formatter) -> Result<(), lib::core::fmt::Error> {
+                let $name(ref value) = self.mask();
+                <$type as Octal>::fmt(value, f)
+            }
+        }
+        impl Binary for $name {
+            fn fmt(&self, f: &mut Formatter) -> Result<(), lib::core::fmt::Error> {
+                let $name(ref value) = self.mask();
+                <$type as Binary>::fmt(value, f)
+            }
+        }
+
+        #[cfg(not(feature = "strict-shifts"))]
+        impl<T> Shr<T> for $name
+        where
+            $type: Shr<T, Output = $type>,
+        {
+            type Output = $name;
+
+            fn shr(self, rhs: T) -> $name {
+                $name(self.mask().0.shr(rhs))
+            }
+        }
+
+        #[cfg(not(feature = "strict-shifts"))]
+        impl<T> Shl<T> for $name
+        where
+            $type: Shl<T, Output = $type>,
+        {
+            type Output = $name;
+
+            fn shl(self, rhs: T) -> $name {
+                $name(self.mask().0.shl(rhs))
+            }
+        }
+
+        #[cfg(not(feature = "strict-shifts"))]
+        impl<T> ShrAssign<T> for $name
+        where
+            $type: ShrAssign<T>,
+        {
+            fn shr_assign(&mut self, rhs: T) {
+                *self = self.mask();
+                self.0.shr_assign(rhs);
+            }
+        }
+
+        #[cfg(not(feature = "strict-shifts"))]
+        impl<T> ShlAssign<T> for $name
+        where
+            $type: ShlAssign<T>,
+        {
+            fn shl_assign(&mut self, rhs: T) {
+                *self = self.mask();
+                self.0.shl_assign(rhs);
+            }
+        }
+
+        // With `strict-shifts` the operators behave like the ones of the built in
+        // types and panic in debug mode when shifting by `Self::BITS` or more.
+        #[cfg(feature = "strict-shifts")]
+        impl<T> Shr<T> for $name
+        where
+            $type: Shr<T, Output = $type>,
+            T: Copy + TryInto<u32>,
+        {
+            type Output = $name;
+
+            fn shr(self, rhs: T) -> $name {
+                debug_assert!(
+                    rhs.try_into().is_ok_and(|n| n < Self::BITS),
+                    "attempt to shift right with overflow"
+                );
+                $name(self.mask().0.shr(rhs))
+            }
+        }
+
+        #[cfg(feature = "strict-shifts")]
+        impl<T> Shl<T> for $name
+        where
+            $type: Shl<T, Output = $type>,
+            T: Copy + TryInto<u32>,
+        {
+            type Output = $name;
+
+            fn shl(self, rhs: T) -> $name {
+                debug_assert!(
+                    rhs.try_into().is_ok_and(|n| n < Self::BITS),
+                    "attempt to shift left with overflow"
+                );
+                $name(self.mask().0.shl(rhs))
+            }
+        }
+
+        #[cfg(feature = "strict-shifts")]
+        impl<T> ShrAssign<T> for $name
+        where
+            $type: ShrAssign<T>,
+            T: Copy + TryInto<u32>,
+        {
+            fn shr_assign(&mut self, rhs: T) {
+                debug_assert!(
+                    rhs.try_into().is_ok_and(|n| n < Self::BITS),
+                    "attempt to shift right with overflow"
+                );
+                *self = self.mask();
+                self.0.shr_assign(rhs);
+            }
+        }
+
+        #[cfg(feature = "strict-shifts")]
+        impl<T> ShlAssign<T> for $name
+        where
+            $type: ShlAssign<T>,
+            T: Copy + TryInto<u32>,
+        {
+            fn shl_assign(&mut self, rhs: T) {
+                debug_assert!(
+                    rhs.try_into().is_ok_and(|n| n < Self::BITS),
+                    "attempt to shift left with overflow"
+                );
+                *self = self.mask();
+                self.0.shl_assign(rhs);
+            }
+        }
+
+        impl BitOr<$name> for $name {
+            type Output = $name;
+
+            fn bitor(self, rhs: $name) -> Self::Output {
+                $name(self.mask().0.bitor(rhs.mask().0))
+            }
+        }
+
+        impl<'a> BitOr<&'a $name> for $name {
+            type Output = <$name as BitOr<$name>>::Output;
+
+            fn bitor(self, rhs: &'a $name) -> Self::Output {
+                $name(self.mask().0.bitor(rhs.mask().0))
+            }
+        }
+
+        impl<'a> BitOr<$name> for &'a $name {
+            type Output = <$name as BitOr<$name>>::Output;
+
+            fn bitor(self, rhs: $name) -> Self::Output {
+                $name(self.mask().0.bitor(rhs.mask().0))
+            }
+        }
+
+        impl<'a> BitOr<&'a $name> for &'a $name {
+            type Output = <$name as BitOr<$name>>::Output;
+
+            fn bitor(self, rhs: &'a $name) -> Self::Output {
+                $name(self.mask().0.bitor(rhs.mask().0))
+            }
+        }
+
+        /// The bits of the right hand side above the width of this type are ignored.
+        impl BitOr<$type> for $name {
+            type Output = $name;
+
+            fn bitor(self, rhs: $type) -> Self::Output {
+                $name(self.mask().0.bitor($name(rhs).mask().0))
+            }
+        }
+
+        impl<'a> BitOr<$type> for &'a $name {
+            type Output = <$name as BitOr<$type>>::Output;
+
+            fn bitor(self, rhs: $type) -> Self::Output {
+                $name(self.mask().0.bitor($name(rhs).mask().0))
+            }
+        }
+
+        impl BitOrAssign<$name> for $name {
+            fn bitor_assign(&mut self, other: $name) {
+                *self = self.mask();
+                self.0.bitor_assign(other.mask().0)
+            }
+        }
+
+        /// The bits of the right hand side above the width of this type are ignored.
+        impl BitOrAssign<$type> for $name {
+            fn bitor_assign(&mut self, other: $type) {
+                *self = self.mask();
+                self.0.bitor_assign($name(other).mask().0)
+            }
+        }
+
+        impl BitXor<$name> for $name {
+            type Output = $name;
+
+            fn bitxor(self, rhs: $name) -> Self::Output {
+                $name(self.mask().0.bitxor(rhs.mask().0))
+            }
+        }
+
+        impl<'a> BitXor<&'a $name> for $name {
+            type Output = <$name as BitXor<$name>>::Output;
+
+            fn bitxor(self, rhs: &'a $name) -> Self::Output {
+                $name(self.mask().0.bitxor(rhs.mask().0))
+            }
+        }
+
+        impl<'a> BitXor<$name> for &'a $name {
+            type Output = <$name as BitXor<$name>>::Output;
+
+            fn bitxor(self, rhs: $name) -> Self::Output {
+                $name(self.mask().0.bitxor(rhs.mask().0))
+            }
+        }
+
+        impl<'a> BitXor<&'a $name> for &'a $name {
+            type Output = <$name as BitXor<$name>>::Output;
+
+            fn bitxor(self, rhs: &'a $name) -> Self::Output {
+                $name(self.mask().0.bitxor(rhs.mask().0))
+            }
+        }
+
+        /// The bits of the right hand side above the width of this type are ignored.
+        impl BitXor<$type> for $name {
+            type Output = $name;
+
+            fn bitxor(self, rhs: $type) -> Self::Output {
+                $name(self.mask().0.bitxor($name(rhs).mask().0))
+            }
+        }
+
+        impl<'a> BitXor<$type> for &'a $name {
+            type Output = <$name as BitXor<$type>>::Output;
+
+            fn bitxor(self, rhs: $type) -> Self::Output {
+                $name(self.mask().0.bitxor($name(rhs).mask().0))
+            }
+        }
+
+        impl BitXorAssign<$name> for $name {
+            fn bitxor_assign(&mut self, other: $name) {
+                *self = self.mask();
+                self.0.bitxor_assign(other.mask().0)
+            }
+        }
+
+        /// The bits of the right hand side above the width of this type are ignored.
+        impl BitXorAssign<$type> for $name {
+            fn bitxor_assign(&mut self, other: $type) {
+                *self = self.mask();
+                self.0.bitxor_assign($name(other).mask().0)
+            }
+        }
+
+        impl Not for $name {
+            type Output = $name;
+
+            fn not(self) -> $name {
+                $name(self.mask().0.not()).mask()
+            }
+        }
+
+        impl<'a> Not for &'a $name {
+            type Output = <$name as Not>::Output;
+
+            fn not(self) -> $name {
+                $name(self.mask().0.not()).mask()
+            }
+        }
+
+        impl BitAnd<$name> for $name {
+            type Output = $name;
+
+            fn bitand(self, rhs: $name) -> Self::Output {
+                $name(self.mask().0.bitand(rhs.mask().0))
+            }
+        }
+
+        impl<'a> BitAnd<&'a $name> for $name {
+            type Output = <$name as BitAnd<$name>>::Output;
+
+            fn bitand(self, rhs: &'a $name) -> Self::Output {
+                $name(self.mask().0.bitand(rhs.mask().0))
+            }
+        }
+
+        impl<'a> BitAnd<$name> for &'a $name {
+            type Output = <$name as BitAnd<$name>>::Output;
+
+            fn bitand(self, rhs: $name) -> Self::Output {
+                $name(self.mask().0.bitand(rhs.mask().0))
+            }
+        }
+
+        impl<'a> BitAnd<&'a $name> for &'a $name {
+            type Output = <$name as BitAnd<$name>>::Output;
+
+            fn bitand(self, rhs: &'a $name) -> Self::Output {
+                $name(self.mask().0.bitand(rhs.mask().0))
+            }
+        }
+
+        /// The bits of the right hand side above the width of this type are ignored.
+        impl BitAnd<$type> for $name {
+            type Output = $name;
+
+            fn bitand(self, rhs: $type) -> Self::Output {
+                $name(self.mask().0.bitand($name(rhs).mask().0))
+            }
+        }
+
+        impl<'a> BitAnd<$type> for &'a $name {
+            type Output = <$name as BitAnd<$type>>::Output;
+
+            fn bitand(self, rhs: $type) -> Self::Output {
+                $name(self.mask().0.bitand($name(rhs).mask().0))
+            }
+        }
+
+        impl BitAndAssign<$name> for $name {
+            fn bitand_assign(&mut self, other: $name) {
+                *self = self.mask();
+                self.0.bitand_assign(other.mask().0)
+            }
+        }
+
+        /// The bits of the right hand side above the width of this type are ignored.
+        impl BitAndAssign<$type> for $name {
+            fn bitand_assign(&mut self, other: $type) {
+                *self = self.mask();
+                self.0.bitand_assign($name(other).mask().0)
+            }
+        }
+
+        impl lib::core::ops::Add<$name> for $name {
+            type Output = $name;
+
+            fn add(self, other: $name) -> $name {
+                let (sum, overflow) = self.overflowing_add(other);
+                debug_assert!(!overflow, "attempt to add with overflow");
+                sum
+            }
+        }
+
+        impl<'a> lib::core::ops::Add<&'a $name> for $name {
+            type Output = <$name as lib::core::ops::Add<$name>>::Output;
+
+            fn add(self, other: &'a $name) -> Self::Output {
+                self + *other
+            }
+        }
+
+        impl<'a> lib::core::ops::Add<$name> for &'a $name {
+            type Output = <$name as lib::core::ops::Add<$name>>::Output;
+
+            fn add(self, other: $name) -> Self::Output {
+                *self + other
+            }
+        }
+
+        impl<'a> lib::core::ops::Add<&'a $name> for &'a $name {
+            type Output = <$name as lib::core::ops::Add<$name>>::Output;
+
+            fn add(self, other: &'a $name) -> Self::Output {
+                *self + *other
+            }
+        }
+
+        impl lib::core::ops::Sub<$name> for $name {
+            type Output = $name;
+
+            fn sub(self, other: $name) -> $name {
+                let (difference, overflow) = self.overflowing_sub(other);
+                debug_assert!(!overflow, "attempt to subtract with overflow");
+                difference
+            }
+        }
+
+        impl<'a> lib::core::ops::Sub<&'a $name> for $name {
+            type Output = <$name as lib::core::ops::Sub<$name>>::Output;
+
+            fn sub(self, other: &'a $name) -> Self::Output {
+                self - *other
+            }
+        }
+
+        impl<'a> lib::core::ops::Sub<$name> for &'a $name {
+            type Output = <$name as lib::core::ops::Sub<$name>>::Output;
+
+            fn sub(self, other: $name) -> Self::Output {
+                *self - other
+            }
+        }
+
+        impl<'a> lib::core::ops::Sub<&'a $name> for &'a $name {
+            type Output = <$name as lib::core::ops::Sub<$name>>::Output;
+
+            fn sub(self, other: &'a $name) -> Self::Output {
+                *self - *other
+            }
+        }
+
+        impl lib::core::ops::Mul<$name> for $name {
+            type Output = $name;
+
+            fn mul(self, other: $name) -> $name {
+                let (product, overflow) = self.overflowing_mul(other);
+                debug_assert!(!overflow, "attempt to multiply with overflow");
+                product
+            }
+        }
+
+        impl<'a> lib::core::ops::Mul<&'a $name> for $name {
+            type Output = <$name as lib::core::ops::Mul<$name>>::Output;
+
+            fn mul(self, other: &'a $name) -> Self::Output {
+                self * *other
+            }
+        }
+
+        impl<'a> lib::core::ops::Mul<$name> for &'a $name {
+            type Output = <$name as lib::core::ops::Mul<$name>>::Output;
+
+            fn mul(self, other: $name) -> Self::Output {
+                *self * other
+            }
+        }
+
+        impl<'a> lib::core::ops::Mul<&'a $name> for &'a $name {
+            type Output = <$name as lib::core::ops::Mul<$name>>::Output;
+
+            fn mul(self, other: &'a $name) -> Self::Output {
+                *self * *other
+            }
+        }
+
+        impl lib::core::ops::Div<$name> for $name {
+            type Output = $name;
+
+            /// Like for the built in types, dividing by zero or dividing `Self::MIN` by
+            /// `-1` panics, also in release mode.
+            fn div(self, other: $name) -> $name {
+                let quotient = self.mask().0 / other.mask().0;
+                let wrapped = $name(quotient).mask();
+                assert!(wrapped.0 == quotient, "attempt to divide with overflow");
+                wrapped
+            }
+        }
+
+        impl lib::core::ops::Rem<$name> for $name {
+            type Output = $name;
+
+            /// Like for the built in types, taking the remainder of a division by zero or
+            /// of `Self::MIN` divided by `-1` panics, also in release mode.
+            fn rem(self, other: $name) -> $name {
+                let remainder = self.mask().0 % other.mask().0;
+                assert!(
+                    self.checked_div(other).is_some(),
+                    "attempt to calculate the remainder with overflow"
+                );
+                $name(remainder)
+            }
+        }
+
+        impl<'a> lib::core::ops::Div<&'a $name> for $name {
+            type Output = <$name as lib::core::ops::Div<$name>>::Output;
+
+            fn div(self, other: &'a $name) -> Self::Output {
+                self / *other
+            }
+        }
+
+        impl<'a> lib::core::ops::Div<$name> for &'a $name {
+            type Output = <$name as lib::core::ops::Div<$name>>::Output;
+
+            fn div(self, other: $name) -> Self::Output {
+                *self / other
+            }
+        }
+
+        impl<'a> lib::core::ops::Div<&'a $name> for &'a $name {
+            type Output = <$name as lib::core::ops::Div<$name>>::Output;
+
+            fn div(self, other: &'a $name) -> Self::Output {
+                *self / *other
+            }
+        }
+
+        impl<'a> lib::core::ops::Rem<&'a $name> for $name {
+            type Output = <$name as lib::core::ops::Rem<$name>>::Output;
+
+            fn rem(self, other: &'a $name) -> Self::Output {
+                self % *other
+            }
+        }
+
+        impl<'a> lib::core::ops::Rem<$name> for &'a $name {
+            type Output = <$name as lib::core::ops::Rem<$name>>::Output;
+
+            fn rem(self, other: $name) -> Self::Output {
+                *self % other
+            }
+        }
+
+        impl<'a> lib::core::ops::Rem<&'a $name> for &'a $name {
+            type Output = <$name as lib::core::ops::Rem<$name>>::Output;
+
+            fn rem(self, other: &'a $name) -> Self::Output {
+                *self % *other
+            }
+        }
+
+        impl lib::core::ops::AddAssign<$name> for $name {
+            /// Like `+`, this panics on overflow in debug mode and wraps in release mode.
+            fn add_assign(&mut self, other: $name) {
+                *self = *self + other;
+            }
+        }
+
+        impl lib::core::ops::SubAssign<$name> for $name {
+            /// Like `-`, this panics on overflow in debug mode and wraps in release mode.
+            fn sub_assign(&mut self, other: $name) {
+                *self = *self - other;
+            }
+        }
+
+        impl lib::core::ops::MulAssign<$name> for $name {
+            /// Like `*`, this panics on overflow in debug mode and wraps in release mode.
+            fn mul_assign(&mut self, other: $name) {
+                *self = *self * other;
+            }
+        }
+
+        impl lib::core::ops::DivAssign<$name> for $name {
+            /// Like `/`, this panics on division by zero and on overflow.
+            fn div_assign(&mut self, other: $name) {
+                *self = *self / other;
+            }
+        }
+
+        impl lib::core::ops::RemAssign<$name> for $name {
+            /// Like `%`, this panics on division by zero and on overflow.
+            fn rem_assign(&mut self, other: $name) {
+                *self = *self % other;
+            }
+        }
+    };
+}
+
+define_unsigned!(#[doc="The 1-bit unsigned integer type."], u1, 1, u8);
+define_unsigned!(#[doc="The 2-bit unsigned integer type."], u2, 2, u8);
+define_unsigned!(#[doc="The 3-bit unsigned integer type."], u3, 3, u8);
+define_unsigned!(#[doc="The 4-bit unsigned integer type."], u4, 4, u8);
+define_unsigned!(#[doc="The 5-bit unsigned integer type."], u5, 5, u8);
+define_unsigned!(#[doc="The 6-bit unsigned integer type."], u6, 6, u8);
+define_unsigned!(#[doc="The 7-bit unsigned integer type."], u7, 7, u8);
+
+define_unsigned!(#[doc="The 9-bit unsigned integer type."], u9, 9, u16);
+define_unsigned!(#[doc="The 10-bit unsigned integer type."], u10, 10, u16);
+define_unsigned!(#[doc="The 11-bit unsigned integer type."], u11, 11, u16);
+define_unsigned!(#[doc="The 12-bit unsigned integer type."], u12, 12, u16);
+define_unsigned!(#[doc="The 13-bit unsigned integer type."], u13, 13, u16);
+define_unsigned!(#[doc="The 14-bit unsigned integer type."], u14, 14, u16);
+define_unsigned!(#[doc="The 15-bit unsigned integer type."], u15, 15, u16);
+
+define_unsigned!(#[doc="The 17-bit unsigned integer type."], u17, 17, u32);
+define_unsigned!(#[doc="The 18-bit unsigned integer type."], u18, 18, u32);
+define_unsigned!(#[doc="The 19-bit unsigned integer type."], u19, 19, u32);
+define_unsigned!(#[doc="The 20-bit unsigned integer type."], u20, 20, u32);
+define_unsigned!(#[doc="The 21-bit unsigned integer type."], u21, 21, u32);
+define_unsigned!(#[doc="The 22-bit unsigned integer type."], u22, 22, u32);
+define_unsigned!(#[doc="The 23-bit unsigned integer type."], u23, 23, u32);
+define_unsigned!(#[doc="The 24-bit unsigned integer type."], u24, 24, u32);
+
+define_unsigned!(#[doc="The 25-bit unsigned integer type."], u25, 25, u32);
+define_unsigned!(#[doc="The 26-bit unsigned integer type."], u26, 26, u32);
+define_unsigned!(#[doc="The 27-bit unsigned integer type."], u27, 27, u32);
+define_unsigned!(#[doc="The 28-bit unsigned integer type."], u28, 28, u32);
+define_unsigned!(#[doc="The 29-bit unsigned integer type."], u29, 29, u32);
+define_unsigned!(#[doc="The 30-bit unsigned integer type."], u30, 30, u32);
+define_unsigned!(#[doc="The 31-bit unsigned integer type."], u31, 31, u32);
+
+define_unsigned!(#[doc="The 33-bit unsigned integer type."], u33, 33, u64);
+define_unsigned!(#[doc="The 34-bit unsigned integer type."], u34, 34, u64);
+define_unsigned!(#[doc="The 35-bit unsigned integer type."], u35, 35, u64);
+define_unsigned!(#[doc="The 36-bit unsigned integer type."], u36, 36, u64);
+define_unsigned!(#[doc="The 37-bit unsigned integer type."], u37, 37, u64);
+define_unsigned!(#[doc="The 38-bit unsigned integer type."], u38, 38, u64);
+define_unsigned!(#[doc="The 39-bit unsigned integer type."], u39, 39, u64);
+define_unsigned!(#[doc="The 40-bit unsigned integer type."], u40, 40, u64);
+
+define_unsigned!(#[doc="The 41-bit unsigned integer type."], u41, 41, u64);
+define_unsigned!(#[doc="The 42-bit unsigned integer type."], u42, 42, u64);
+define_unsigned!(#[doc="The 43-bit unsigned integer type."], u43, 43, u64);
+define_unsigned!(#[doc="The 44-bit unsigned integer type."], u44, 44, u64);
+define_unsigned!(#[doc="The 45-bit unsigned integer type."], u45, 45, u64);
+define_unsigned!(#[doc="The 46-bit unsigned integer type."], u46, 46, u64);
+define_unsigned!(#[doc="The 47-bit unsigned integer type."], u47, 47, u64);
+define_unsigned!(#[doc="The 48-bit unsigned integer type."], u48, 48, u64);
+
+define_unsigned!(#[doc="The 49-bit unsigned integer type."], u49, 49, u64);
+define_unsigned!(#[doc="The 50-bit unsigned integer type."], u50, 50, u64);
+define_unsigned!(#[doc="The 51-bit unsigned integer type."], u51, 51, u64);
+define_unsigned!(#[doc="The 52-bit unsigned integer type."], u52, 52, u64);
+define_unsigned!(#[doc="The 53-bit unsigned integer type."], u53, 53, u64);
+define_unsigned!(#[doc="The 54-bit unsigned integer type."], u54, 54, u64);
+define_unsigned!(#[doc="The 55-bit unsigned integer type."], u55, 55, u64);
+define_unsigned!(#[doc="The 56-bit unsigned integer type."], u56, 56, u64);
+
+define_unsigned!(#[doc="The 57-bit unsigned integer type."], u57, 57, u64);
+define_unsigned!(#[doc="The 58-bit unsigned integer type."], u58, 58, u64);
+define_unsigned!(#[doc="The 59-bit unsigned integer type."], u59, 59, u64);
+define_unsigned!(#[doc="The 60-bit unsigned integer type."], u60, 60, u64);
+define_unsigned!(#[doc="The 61-bit unsigned integer type."], u61, 61, u64);
+define_unsigned!(#[doc="The 62-bit unsigned integer type."], u62, 62, u64);
+define_unsigned!(#[doc="The 63-bit unsigned integer type."], u63, 63, u64);
+
+define_unsigned!(#[doc="The 65-bit unsigned integer type."], u65, 65, u128);
+define_unsigned!(#[doc="The 66-bit unsigned integer type."], u66, 66, u128);
+define_unsigned!(#[doc="The 67-bit unsigned integer type."], u67, 67, u128);
+define_unsigned!(#[doc="The 68-bit unsigned integer type."], u68, 68, u128);
+define_unsigned!(#[doc="The 69-bit unsigned integer type."], u69, 69, u128);
+define_unsigned!(#[doc="The 70-bit unsigned integer type."], u70, 70, u128);
+define_unsigned!(#[doc="The 71-bit unsigned integer type."], u71, 71, u128);
+define_unsigned!(#[doc="The 72-bit unsigned integer type."], u72, 72, u128);
+
+define_unsigned!(#[doc="The 73-bit unsigned integer type."], u73, 73, u128);
+define_unsigned!(#[doc="The 74-bit unsigned integer type."], u74, 74, u128);
 define_unsigned!(#[doc="The 75-bit unsigned integer type."], u75, 75, u128);
 define_unsigned!(#[doc="The 76-bit unsigned integer type."], u76, 76, u128);
 define_unsigned!(#[doc="The 77-bit unsigned integer type."], u77, 77, u128);
@@ -504,463 +2071,3468 @@ define_unsigned!(#[doc="The 78-bit unsigned integer type."], u78, 78, u128);
 define_unsigned!(#[doc="The 79-bit unsigned integer type."], u79, 79, u128);
 define_unsigned!(#[doc="The 80-bit unsigned integer type."], u80, 80, u128);
 
-define_unsigned!(#[doc="The 81-bit unsigned integer type."], u81, 81, u128);
-define_unsigned!(#[doc="The 82-bit unsigned integer type."], u82, 82, u128);
-define_unsigned!(#[doc="The 83-bit unsigned integer type."], u83, 83, u128);
-define_unsigned!(#[doc="The 84-bit unsigned integer type."], u84, 84, u128);
-define_unsigned!(#[doc="The 85-bit unsigned integer type."], u85, 85, u128);
-define_unsigned!(#[doc="The 86-bit unsigned integer type."], u86, 86, u128);
-define_unsigned!(#[doc="The 87-bit unsigned integer type."], u87, 87, u128);
-define_unsigned!(#[doc="The 88-bit unsigned integer type."], u88, 88, u128);
+define_unsigned!(#[doc="The 81-bit unsigned integer type."], u81, 81, u128);
+define_unsigned!(#[doc="The 82-bit unsigned integer type."], u82, 82, u128);
+define_unsigned!(#[doc="The 83-bit unsigned integer type."], u83, 83, u128);
+define_unsigned!(#[doc="The 84-bit unsigned integer type."], u84, 84, u128);
+define_unsigned!(#[doc="The 85-bit unsigned integer type."], u85, 85, u128);
+define_unsigned!(#[doc="The 86-bit unsigned integer type."], u86, 86, u128);
+define_unsigned!(#[doc="The 87-bit unsigned integer type."], u87, 87, u128);
+define_unsigned!(#[doc="The 88-bit unsigned integer type."], u88, 88, u128);
+
+define_unsigned!(#[doc="The 89-bit unsigned integer type."], u89, 89, u128);
+define_unsigned!(#[doc="The 90-bit unsigned integer type."], u90, 90, u128);
+define_unsigned!(#[doc="The 91-bit unsigned integer type."], u91, 91, u128);
+define_unsigned!(#[doc="The 92-bit unsigned integer type."], u92, 92, u128);
+define_unsigned!(#[doc="The 93-bit unsigned integer type."], u93, 93, u128);
+define_unsigned!(#[doc="The 94-bit unsigned integer type."], u94, 94, u128);
+define_unsigned!(#[doc="The 95-bit unsigned integer type."], u95, 95, u128);
+define_unsigned!(#[doc="The 96-bit unsigned integer type."], u96, 96, u128);
+
+define_unsigned!(#[doc="The 97-bit unsigned integer type."], u97, 97, u128);
+define_unsigned!(#[doc="The 98-bit unsigned integer type."], u98, 98, u128);
+define_unsigned!(#[doc="The 99-bit unsigned integer type."], u99, 99, u128);
+define_unsigned!(#[doc="The 100-bit unsigned integer type."], u100, 100, u128);
+define_unsigned!(#[doc="The 101-bit unsigned integer type."], u101, 101, u128);
+define_unsigned!(#[doc="The 102-bit unsigned integer type."], u102, 102, u128);
+define_unsigned!(#[doc="The 103-bit unsigned integer type."], u103, 103, u128);
+define_unsigned!(#[doc="The 104-bit unsigned integer type."], u104, 104, u128);
+
+define_unsigned!(#[doc="The 105-bit unsigned integer type."], u105, 105, u128);
+define_unsigned!(#[doc="The 106-bit unsigned integer type."], u106, 106, u128);
+define_unsigned!(#[doc="The 107-bit unsigned integer type."], u107, 107, u128);
+define_unsigned!(#[doc="The 108-bit unsigned integer type."], u108, 108, u128);
+define_unsigned!(#[doc="The 109-bit unsigned integer type."], u109, 109, u128);
+define_unsigned!(#[doc="The 110-bit unsigned integer type."], u110, 110, u128);
+define_unsigned!(#[doc="The 111-bit unsigned integer type."], u111, 111, u128);
+define_unsigned!(#[doc="The 112-bit unsigned integer type."], u112, 112, u128);
+
+define_unsigned!(#[doc="The 113-bit unsigned integer type."], u113, 113, u128);
+define_unsigned!(#[doc="The 114-bit unsigned integer type."], u114, 114, u128);
+define_unsigned!(#[doc="The 115-bit unsigned integer type."], u115, 115, u128);
+define_unsigned!(#[doc="The 116-bit unsigned integer type."], u116, 116, u128);
+define_unsigned!(#[doc="The 117-bit unsigned integer type."], u117, 117, u128);
+define_unsigned!(#[doc="The 118-bit unsigned integer type."], u118, 118, u128);
+define_unsigned!(#[doc="The 119-bit unsigned integer type."], u119, 119, u128);
+define_unsigned!(#[doc="The 120-bit unsigned integer type."], u120, 120, u128);
+
+define_unsigned!(#[doc="The 121-bit unsigned integer type."], u121, 121, u128);
+define_unsigned!(#[doc="The 122-bit unsigned integer type."], u122, 122, u128);
+define_unsigned!(#[doc="The 123-bit unsigned integer type."], u123, 123, u128);
+define_unsigned!(#[doc="The 124-bit unsigned integer type."], u124, 124, u128);
+define_unsigned!(#[doc="The 125-bit unsigned integer type."], u125, 125, u128);
+define_unsigned!(#[doc="The 126-bit unsigned integer type."], u126, 126, u128);
+define_unsigned!(#[doc="The 127-bit unsigned integer type."], u127, 127, u128);
+
+define_signed!(#[doc="The 1-bit signed integer type."], i1, 1, i8);
+define_signed!(#[doc="The 2-bit signed integer type."], i2, 2, i8);
+define_signed!(#[doc="The 3-bit signed integer type."], i3, 3, i8);
+define_signed!(#[doc="The 4-bit signed integer type."], i4, 4, i8);
+define_signed!(#[doc="The 5-bit signed integer type."], i5, 5, i8);
+define_signed!(#[doc="The 6-bit signed integer type."], i6, 6, i8);
+define_signed!(#[doc="The 7-bit signed integer type."], i7, 7, i8);
+
+define_signed!(#[doc="The 9-bit signed integer type."], i9, 9, i16);
+define_signed!(#[doc="The 10-bit signed integer type."], i10, 10, i16);
+define_signed!(#[doc="The 11-bit signed integer type."], i11, 11, i16);
+define_signed!(#[doc="The 12-bit signed integer type."], i12, 12, i16);
+define_signed!(#[doc="The 13-bit signed integer type."], i13, 13, i16);
+define_signed!(#[doc="The 14-bit signed integer type."], i14, 14, i16);
+define_signed!(#[doc="The 15-bit signed integer type."], i15, 15, i16);
+
+define_signed!(#[doc="The 17-bit signed integer type."], i17, 17, i32);
+define_signed!(#[doc="The 18-bit signed integer type."], i18, 18, i32);
+define_signed!(#[doc="The 19-bit signed integer type."], i19, 19, i32);
+define_signed!(#[doc="The 20-bit signed integer type."], i20, 20, i32);
+define_signed!(#[doc="The 21-bit signed integer type."], i21, 21, i32);
+define_signed!(#[doc="The 22-bit signed integer type."], i22, 22, i32);
+define_signed!(#[doc="The 23-bit signed integer type."], i23, 23, i32);
+define_signed!(#[doc="The 24-bit signed integer type."], i24, 24, i32);
+
+define_signed!(#[doc="The 25-bit signed integer type."], i25, 25, i32);
+define_signed!(#[doc="The 26-bit signed integer type."], i26, 26, i32);
+define_signed!(#[doc="The 27-bit signed integer type."], i27, 27, i32);
+define_signed!(#[doc="The 28-bit signed integer type."], i28, 28, i32);
+define_signed!(#[doc="The 29-bit signed integer type."], i29, 29, i32);
+define_signed!(#[doc="The 30-bit signed integer type."], i30, 30, i32);
+define_signed!(#[doc="The 31-bit signed integer type."], i31, 31, i32);
+
+define_signed!(#[doc="The 33-bit signed integer type."], i33, 33, i64);
+define_signed!(#[doc="The 34-bit signed integer type."], i34, 34, i64);
+define_signed!(#[doc="The 35-bit signed integer type."], i35, 35, i64);
+define_signed!(#[doc="The 36-bit signed integer type."], i36, 36, i64);
+define_signed!(#[doc="The 37-bit signed integer type."], i37, 37, i64);
+define_signed!(#[doc="The 38-bit signed integer type."], i38, 38, i64);
+define_signed!(#[doc="The 39-bit signed integer type."], i39, 39, i64);
+define_signed!(#[doc="The 40-bit signed integer type."], i40, 40, i64);
+
+define_signed!(#[doc="The 41-bit signed integer type."], i41, 41, i64);
+define_signed!(#[doc="The 42-bit signed integer type."], i42, 42, i64);
+define_signed!(#[doc="The 43-bit signed integer type."], i43, 43, i64);
+define_signed!(#[doc="The 44-bit signed integer type."], i44, 44, i64);
+define_signed!(#[doc="The 45-bit signed integer type."], i45, 45, i64);
+define_signed!(#[doc="The 46-bit signed integer type."], i46, 46, i64);
+define_signed!(#[doc="The 47-bit signed integer type."], i47, 47, i64);
+define_signed!(#[doc="The 48-bit signed integer type."], i48, 48, i64);
+
+define_signed!(#[doc="The 49-bit signed integer type."], i49, 49, i64);
+define_signed!(#[doc="The 50-bit signed integer type."], i50, 50, i64);
+define_signed!(#[doc="The 51-bit signed integer type."], i51, 51, i64);
+define_signed!(#[doc="The 52-bit signed integer type."], i52, 52, i64);
+define_signed!(#[doc="The 53-bit signed integer type."], i53, 53, i64);
+define_signed!(#[doc="The 54-bit signed integer type."], i54, 54, i64);
+define_signed!(#[doc="The 55-bit signed integer type."], i55, 55, i64);
+define_signed!(#[doc="The 56-bit signed integer type."], i56, 56, i64);
+
+define_signed!(#[doc="The 57-bit signed integer type."], i57, 57, i64);
+define_signed!(#[doc="The 58-bit signed integer type."], i58, 58, i64);
+define_signed!(#[doc="The 59-bit signed integer type."], i59, 59, i64);
+define_signed!(#[doc="The 60-bit signed integer type."], i60, 60, i64);
+define_signed!(#[doc="The 61-bit signed integer type."], i61, 61, i64);
+define_signed!(#[doc="The 62-bit signed integer type."], i62, 62, i64);
+define_signed!(#[doc="The 63-bit signed integer type."], i63, 63, i64);
+
+define_signed!(#[doc="The 65-bit signed integer type."], i65, 65, i128);
+define_signed!(#[doc="The 66-bit signed integer type."], i66, 66, i128);
+define_signed!(#[doc="The 67-bit signed integer type."], i67, 67, i128);
+define_signed!(#[doc="The 68-bit signed integer type."], i68, 68, i128);
+define_signed!(#[doc="The 69-bit signed integer type."], i69, 69, i128);
+define_signed!(#[doc="The 70-bit signed integer type."], i70, 70, i128);
+define_signed!(#[doc="The 71-bit signed integer type."], i71, 71, i128);
+define_signed!(#[doc="The 72-bit signed integer type."], i72, 72, i128);
+
+define_signed!(#[doc="The 73-bit signed integer type."], i73, 73, i128);
+define_signed!(#[doc="The 74-bit signed integer type."], i74, 74, i128);
+define_signed!(#[doc="The 75-bit signed integer type."], i75, 75, i128);
+define_signed!(#[doc="The 76-bit signed integer type."], i76, 76, i128);
+define_signed!(#[doc="The 77-bit signed integer type."], i77, 77, i128);
+define_signed!(#[doc="The 78-bit signed integer type."], i78, 78, i128);
+define_signed!(#[doc="The 79-bit signed integer type."], i79, 79, i128);
+define_signed!(#[doc="The 80-bit signed integer type."], i80, 80, i128);
+
+define_signed!(#[doc="The 81-bit signed integer type."], i81, 81, i128);
+define_signed!(#[doc="The 82-bit signed integer type."], i82, 82, i128);
+define_signed!(#[doc="The 83-bit signed integer type."], i83, 83, i128);
+define_signed!(#[doc="The 84-bit signed integer type."], i84, 84, i128);
+define_signed!(#[doc="The 85-bit signed integer type."], i85, 85, i128);
+define_signed!(#[doc="The 86-bit signed integer type."], i86, 86, i128);
+define_signed!(#[doc="The 87-bit signed integer type."], i87, 87, i128);
+define_signed!(#[doc="The 88-bit signed integer type."], i88, 88, i128);
+
+define_signed!(#[doc="The 89-bit signed integer type."], i89, 89, i128);
+define_signed!(#[doc="The 90-bit signed integer type."], i90, 90, i128);
+define_signed!(#[doc="The 91-bit signed integer type."], i91, 91, i128);
+define_signed!(#[doc="The 92-bit signed integer type."], i92, 92, i128);
+define_signed!(#[doc="The 93-bit signed integer type."], i93, 93, i128);
+define_signed!(#[doc="The 94-bit signed integer type."], i94, 94, i128);
+define_signed!(#[doc="The 95-bit signed integer type."], i95, 95, i128);
+define_signed!(#[doc="The 96-bit signed integer type."], i96, 96, i128);
+
+define_signed!(#[doc="The 97-bit signed integer type."], i97, 97, i128);
+define_signed!(#[doc="The 98-bit signed integer type."], i98, 98, i128);
+define_signed!(#[doc="The 99-bit signed integer type."], i99, 99, i128);
+define_signed!(#[doc="The 100-bit signed integer type."], i100, 100, i128);
+define_signed!(#[doc="The 101-bit signed integer type."], i101, 101, i128);
+define_signed!(#[doc="The 102-bit signed integer type."], i102, 102, i128);
+define_signed!(#[doc="The 103-bit signed integer type."], i103, 103, i128);
+define_signed!(#[doc="The 104-bit signed integer type."], i104, 104, i128);
+
+define_signed!(#[doc="The 105-bit signed integer type."], i105, 105, i128);
+define_signed!(#[doc="The 106-bit signed integer type."], i106, 106, i128);
+define_signed!(#[doc="The 107-bit signed integer type."], i107, 107, i128);
+define_signed!(#[doc="The 108-bit signed integer type."], i108, 108, i128);
+define_signed!(#[doc="The 109-bit signed integer type."], i109, 109, i128);
+define_signed!(#[doc="The 110-bit signed integer type."], i110, 110, i128);
+define_signed!(#[doc="The 111-bit signed integer type."], i111, 111, i128);
+define_signed!(#[doc="The 112-bit signed integer type."], i112, 112, i128);
+
+define_signed!(#[doc="The 113-bit signed integer type."], i113, 113, i128);
+define_signed!(#[doc="The 114-bit signed integer type."], i114, 114, i128);
+define_signed!(#[doc="The 115-bit signed integer type."], i115, 115, i128);
+define_signed!(#[doc="The 116-bit signed integer type."], i116, 116, i128);
+define_signed!(#[doc="The 117-bit signed integer type."], i117, 117, i128);
+define_signed!(#[doc="The 118-bit signed integer type."], i118, 118, i128);
+define_signed!(#[doc="The 119-bit signed integer type."], i119, 119, i128);
+define_signed!(#[doc="The 120-bit signed integer type."], i120, 120, i128);
+
+define_signed!(#[doc="The 121-bit signed integer type."], i121, 121, i128);
+define_signed!(#[doc="The 122-bit signed integer type."], i122, 122, i128);
+define_signed!(#[doc="The 123-bit signed integer type."], i123, 123, i128);
+define_signed!(#[doc="The 124-bit signed integer type."], i124, 124, i128);
+define_signed!(#[doc="The 125-bit signed integer type."], i125, 125, i128);
+define_signed!(#[doc="The 126-bit signed integer type."], i126, 126, i128);
+define_signed!(#[doc="The 127-bit signed integer type."], i127, 127, i128);
+
+macro_rules! implement_signed_diff {
+    {$($name:ident => $signed:ident),*} => {$(
+        impl $name {
+            /// Subtraction into the signed type that is one bit wider.
+            /// Computes `self - rhs` without wrapping.
+            ///
+            /// The difference of any two values of this type, from `MIN - MAX` to
+            /// `MAX - MIN`, always fits in the wider type, so this cannot overflow.
+            pub fn signed_diff(self, rhs: Self) -> $signed {
+                $signed((self.as_i128() - rhs.as_i128()) as _)
+            }
+        }
+    )*};
+    {primitive $($name:ident => $signed:ident),*} => {$(
+        impl $name {
+            /// Subtraction into the signed type that is one bit wider.
+            /// Computes `self - rhs` without wrapping.
+            ///
+            /// The difference of any two values of this type, from `MIN - MAX` to
+            /// `MAX - MIN`, always fits in the wider type, so this cannot overflow.
+            pub fn signed_diff(self, rhs: Self) -> $signed {
+                (self.as_i128() - rhs.as_i128()) as $signed
+            }
+        }
+    )*};
+}
+
+implement_signed_diff!(
+    u1 => i2, u2 => i3, u3 => i4, u4 => i5, u5 => i6, u6 => i7, u9 => i10, u10 => i11, u11 => i12,
+    u12 => i13, u13 => i14, u14 => i15, u17 => i18, u18 => i19, u19 => i20, u20 => i21, u21 => i22,
+    u22 => i23, u23 => i24, u24 => i25, u25 => i26, u26 => i27, u27 => i28, u28 => i29, u29 => i30,
+    u30 => i31, u33 => i34, u34 => i35, u35 => i36, u36 => i37, u37 => i38, u38 => i39, u39 => i40,
+    u40 => i41, u41 => i42, u42 => i43, u43 => i44, u44 => i45, u45 => i46, u46 => i47, u47 => i48,
+    u48 => i49, u49 => i50, u50 => i51, u51 => i52, u52 => i53, u53 => i54, u54 => i55, u55 => i56,
+    u56 => i57, u57 => i58, u58 => i59, u59 => i60, u60 => i61, u61 => i62, u62 => i63, u65 => i66,
+    u66 => i67, u67 => i68, u68 => i69, u69 => i70, u70 => i71, u71 => i72, u72 => i73, u73 => i74,
+    u74 => i75, u75 => i76, u76 => i77, u77 => i78, u78 => i79, u79 => i80, u80 => i81, u81 => i82,
+    u82 => i83, u83 => i84, u84 => i85, u85 => i86, u86 => i87, u87 => i88, u88 => i89, u89 => i90,
+    u90 => i91, u91 => i92, u92 => i93, u93 => i94, u94 => i95, u95 => i96, u96 => i97, u97 => i98,
+    u98 => i99, u99 => i100, u100 => i101, u101 => i102, u102 => i103, u103 => i104, u104 => i105,
+    u105 => i106, u106 => i107, u107 => i108, u108 => i109, u109 => i110, u110 => i111,
+    u111 => i112, u112 => i113, u113 => i114, u114 => i115, u115 => i116, u116 => i117,
+    u117 => i118, u118 => i119, u119 => i120, u120 => i121, u121 => i122, u122 => i123,
+    u123 => i124, u124 => i125, u125 => i126, u126 => i127
+);
+implement_signed_diff!(primitive u7 => i8, u15 => i16, u31 => i32, u63 => i64, u127 => i128);
+
+// Implemented outside of `define_signed!` as it names the unsigned type of the same width
+macro_rules! implement_unsigned_abs {
+    {$($name:ident => $unsigned:ident),*} => {$(
+        impl $name {
+            /// Computes the absolute value of `self` as the unsigned type of the same
+            /// width. This never overflows, also not for `Self::MIN`.
+            pub fn unsigned_abs(self) -> $unsigned {
+                $unsigned::new(self.mask().0.unsigned_abs() as _)
+            }
+        }
+    )*};
+}
+
+implement_unsigned_abs!(i1 => u1, i2 => u2, i3 => u3, i4 => u4, i5 => u5, i6 => u6, i7 => u7, i9 => u9, i10 => u10, i11 => u11, i12 => u12, i13 => u13, i14 => u14, i15 => u15, i17 => u17, i18 => u18, i19 => u19, i20 => u20, i21 => u21, i22 => u22, i23 => u23, i24 => u24, i25 => u25, i26 => u26, i27 => u27, i28 => u28, i29 => u29, i30 => u30, i31 => u31, i33 => u33, i34 => u34, i35 => u35, i36 => u36, i37 => u37, i38 => u38, i39 => u39, i40 => u40, i41 => u41, i42 => u42, i43 => u43, i44 => u44, i45 => u45, i46 => u46, i47 => u47, i48 => u48, i49 => u49, i50 => u50, i51 => u51, i52 => u52, i53 => u53, i54 => u54, i55 => u55, i56 => u56, i57 => u57, i58 => u58, i59 => u59, i60 => u60, i61 => u61, i62 => u62, i63 => u63, i65 => u65, i66 => u66, i67 => u67, i68 => u68, i69 => u69, i70 => u70, i71 => u71, i72 => u72, i73 => u73, i74 => u74, i75 => u75, i76 => u76, i77 => u77, i78 => u78, i79 => u79, i80 => u80, i81 => u81, i82 => u82, i83 => u83, i84 => u84, i85 => u85, i86 => u86, i87 => u87, i88 => u88, i89 => u89, i90 => u90, i91 => u91, i92 => u92, i93 => u93, i94 => u94, i95 => u95, i96 => u96, i97 => u97, i98 => u98, i99 => u99, i100 => u100, i101 => u101, i102 => u102, i103 => u103, i104 => u104, i105 => u105, i106 => u106, i107 => u107, i108 => u108, i109 => u109, i110 => u110, i111 => u111, i112 => u112, i113 => u113, i114 => u114, i115 => u115, i116 => u116, i117 => u117, i118 => u118, i119 => u119, i120 => u120, i121 => u121, i122 => u122, i123 => u123, i124 => u124, i125 => u125, i126 => u126, i127 => u127);
+
+macro_rules! implement_canonical_unsigned {
+    {$($unsigned:ident: [$($name:ident),*]),*} => {$($(
+        impl $name {
+            /// Returns the two's complement bit pattern of `self` within `Self::BITS` bits,
+            /// as the unsigned counterpart of the backing type.
+            ///
+            /// The bits above the width are zero instead of copies of the sign bit, so
+            /// `i4::new(-1)` gives `0b1111`.
+            pub fn to_canonical_primitive_unsigned(self) -> $unsigned {
+                self.mask().0 as $unsigned & Self::MASK as $unsigned
+            }
+        }
+    )*)*};
+}
+
+implement_canonical_unsigned! {
+    u8: [i1, i2, i3, i4, i5, i6, i7],
+    u16: [i9, i10, i11, i12, i13, i14, i15],
+    u32: [i17, i18, i19, i20, i21, i22, i23, i24, i25, i26, i27, i28, i29, i30, i31],
+    u64: [i33, i34, i35, i36, i37, i38, i39, i40, i41, i42, i43, i44, i45, i46, i47, i48, i49, i50, i51, i52, i53, i54, i55, i56, i57, i58, i59, i60, i61, i62, i63],
+    u128: [i65, i66, i67, i68, i69, i70, i71, i72, i73, i74, i75, i76, i77, i78, i79, i80, i81, i82, i83, i84, i85, i86, i87, i88, i89, i90, i91, i92, i93, i94, i95, i96, i97, i98, i99, i100, i101, i102, i103, i104, i105, i106, i107, i108, i109, i110, i111, i112, i113, i114, i115, i116, i117, i118, i119, i120, i121, i122, i123, i124, i125, i126, i127]
+}
+
+macro_rules! implement_byte_aligned {
+    {[$($name:ident),*]} => {$(
+        impl $name {
+            /// Swaps the two nibbles of each byte of `self`.
+            ///
+            /// Only available for the types whose width is a multiple of eight, which
+            /// consist of whole bytes.
+            pub fn swap_nibbles(self) -> Self {
+                let bits = self.as_u128() & (u128::MAX >> (128 - Self::BITS));
+                let low = 0x0f0f_0f0f_0f0f_0f0f_0f0f_0f0f_0f0f_0f0fu128;
+                $name((((bits & low) << 4) | ((bits >> 4) & low)) as _).mask()
+            }
+
+            /// Reverses the byte order of `self`.
+            ///
+            /// Only available for the types whose width is a multiple of eight, for which
+            /// the bytes of the value are exactly the bytes of the width.
+            pub fn swap_bytes(self) -> Self {
+                let bits = self.as_u128() & (u128::MAX >> (128 - Self::BITS));
+                $name((bits.swap_bytes() >> (128 - Self::BITS)) as _).mask()
+            }
+        }
+    )*};
+}
+
+implement_byte_aligned!([u24, u40, u48, u56, u72, u80, u88, u96, u104, u112, u120]);
+implement_byte_aligned!([i24, i40, i48, i56, i72, i80, i88, i96, i104, i112, i120]);
+
+macro_rules! implement_packed_bits {
+    {[$($name:ident),*]} => {$(
+        impl $name {
+            /// Returns the bits of `self` in the least significant `Self::BITS` bits of an
+            /// `u64`, together with the number of bits, `Self::BITS`.
+            ///
+            /// The remaining high bits are zero, also for negative values, so the result
+            /// can be appended directly to a bitstream.
+            pub fn to_packed_bits(self) -> (u64, u32) {
+                let bits = self.as_u128() & ((1u128 << Self::BITS) - 1);
+                (bits as u64, Self::BITS)
+            }
+
+            /// Packs `values` into a single `u64`, the value at index `i` occupying the
+            /// bits `i * Self::BITS..(i + 1) * Self::BITS`. Unused high bits are zero.
+            ///
+            /// The result can be stored in an `AtomicU64` to update several small
+            /// counters with a single compare and swap.
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if `values` has more than `64 / Self::BITS`
+            /// elements.
+            pub fn pack_into_u64(values: &[Self]) -> u64 {
+                assert!(
+                    values.len() <= (64 / Self::BITS) as usize,
+                    "too many values to pack into a u64"
+                );
+                values.iter().enumerate().fold(0, |packed, (i, value)| {
+                    packed | (value.to_packed_bits().0 << (i as u32 * Self::BITS))
+                })
+            }
+
+            /// Unpacks the `64 / Self::BITS` values of the layout used by
+            /// [`pack_into_u64`](Self::pack_into_u64).
+            ///
+            /// Slots that were not filled when packing are unpacked as zero.
+            #[cfg(feature = "std")]
+            pub fn unpack_from_u64(packed: u64) -> Vec<Self> {
+                let field = (1u64 << Self::BITS) - 1;
+                (0..64 / Self::BITS)
+                    .map(|i| $name(((packed >> (i * Self::BITS)) & field) as _).mask())
+                    .collect()
+            }
+
+            /// Packs `values` tightly into bytes, least significant bit first.
+            ///
+            /// The values fill the buffer in order. Each value is written starting with
+            /// its least significant bit, and each byte is filled starting at its least
+            /// significant bit, so bit `k` of the stream is bit `k % 8` of byte `k / 8`.
+            /// The unused high bits of the last byte are zero.
+            #[cfg(feature = "std")]
+            pub fn pack_le(values: &[Self]) -> Vec<u8> {
+                let width = Self::BITS as usize;
+                let mut bytes = vec![0u8; (values.len() * width).div_ceil(8)];
+                for (i, value) in values.iter().enumerate() {
+                    let bits = value.to_packed_bits().0;
+                    for j in 0..width {
+                        let k = i * width + j;
+                        bytes[k / 8] |= (((bits >> j) & 1) as u8) << (k % 8);
+                    }
+                }
+                bytes
+            }
+
+            /// Packs `values` tightly into bytes, most significant bit first.
+            ///
+            /// The values fill the buffer in order. Each value is written starting with
+            /// its most significant bit, and each byte is filled starting at its most
+            /// significant bit, so bit `k` of the stream is bit `7 - k % 8` of byte `k / 8`.
+            /// The unused low bits of the last byte are zero.
+            #[cfg(feature = "std")]
+            pub fn pack_be(values: &[Self]) -> Vec<u8> {
+                let width = Self::BITS as usize;
+                let mut bytes = vec![0u8; (values.len() * width).div_ceil(8)];
+                for (i, value) in values.iter().enumerate() {
+                    let bits = value.to_packed_bits().0;
+                    for j in 0..width {
+                        let k = i * width + j;
+                        bytes[k / 8] |= (((bits >> (width - 1 - j)) & 1) as u8) << (7 - k % 8);
+                    }
+                }
+                bytes
+            }
+
+            /// Unpacks `count` values from the layout used by [`pack_le`](Self::pack_le).
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if `bytes` holds fewer than `count * Self::BITS` bits.
+            #[cfg(feature = "std")]
+            pub fn unpack_le(bytes: &[u8], count: usize) -> Vec<Self> {
+                let width = Self::BITS as usize;
+                assert!(
+                    count * width <= bytes.len() * 8,
+                    "not enough bytes to unpack the values"
+                );
+                (0..count)
+                    .map(|i| {
+                        let bits = (0..width).fold(0u64, |bits, j| {
+                            let k = i * width + j;
+                            bits | (u64::from((bytes[k / 8] >> (k % 8)) & 1) << j)
+                        });
+                        $name(bits as _).mask()
+                    })
+                    .collect()
+            }
+
+            /// Unpacks `count` values from the layout used by [`pack_be`](Self::pack_be).
+            ///
+            /// # Panic
+            ///
+            /// This function will panic if `bytes` holds fewer than `count * Self::BITS` bits.
+            #[cfg(feature = "std")]
+            pub fn unpack_be(bytes: &[u8], count: usize) -> Vec<Self> {
+                let width = Self::BITS as usize;
+                assert!(
+                    count * width <= bytes.len() * 8,
+                    "not enough bytes to unpack the values"
+                );
+                (0..count)
+                    .map(|i| {
+                        let bits = (0..width).fold(0u64, |bits, j| {
+                            let k = i * width + j;
+                            (bits << 1) | u64::from((bytes[k / 8] >> (7 - k % 8)) & 1)
+                        });
+                        $name(bits as _).mask()
+                    })
+                    .collect()
+            }
+        }
+    )*};
+}
+
+implement_packed_bits!([
+    u1, u2, u3, u4, u5, u6, u7, u9, u10, u11, u12, u13, u14, u15, u17, u18, u19, u20, u21, u22,
+    u23, u24, u25, u26, u27, u28, u29, u30, u31, u33, u34, u35, u36, u37, u38, u39, u40, u41, u42,
+    u43, u44, u45, u46, u47, u48, u49, u50, u51, u52, u53, u54, u55, u56, u57, u58, u59, u60, u61,
+    u62, u63
+]);
+implement_packed_bits!([
+    i1, i2, i3, i4, i5, i6, i7, i9, i10, i11, i12, i13, i14, i15, i17, i18, i19, i20, i21, i22,
+    i23, i24, i25, i26, i27, i28, i29, i30, i31, i33, i34, i35, i36, i37, i38, i39, i40, i41, i42,
+    i43, i44, i45, i46, i47, i48, i49, i50, i51, i52, i53, i54, i55, i56, i57, i58, i59, i60, i61,
+    i62, i63
+]);
+
+#[cfg(test)]
+#[allow(clippy::op_ref)]
+mod tests {
+    use super::*;
+
+    /// An endless xorshift sequence, the same on every run.
+    fn xorshift() -> impl Iterator<Item = u64> {
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        lib::core::iter::repeat_with(move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        })
+    }
+
+    #[test]
+    fn test_masking() {
+        assert_eq!(u4(0b11000110).mask().0, 0b00000110);
+        assert_eq!(u4(0b00001000).mask().0, 0b00001000);
+        assert_eq!(u4(0b00001110).mask().0, 0b00001110);
+
+        assert_eq!(i4(0b11000110u8 as i8).mask().0, 0b00000110u8 as i8);
+        assert_eq!(i4(0b00001000u8 as i8).mask().0, 0b11111000u8 as i8);
+        assert_eq!(i4(0b00001110u8 as i8).mask().0, 0b11111110u8 as i8);
+    }
+
+    #[test]
+    fn min_max_values() {
+        assert_eq!(u1::MAX, u1(1));
+        assert_eq!(u2::MAX, u2(3));
+        assert_eq!(u3::MAX, u3(7));
+        assert_eq!(u7::MAX, u7(127));
+        assert_eq!(u9::MAX, u9(511));
+
+        assert_eq!(i1::MAX, i1(0));
+        assert_eq!(i2::MAX, i2(1));
+        assert_eq!(i3::MAX, i3(3));
+        assert_eq!(i7::MAX, i7(63));
+        assert_eq!(i9::MAX, i9(255));
+
+        assert_eq!(u1::MIN, u1(0));
+        assert_eq!(u2::MIN, u2(0));
+        assert_eq!(u3::MIN, u3(0));
+        assert_eq!(u7::MIN, u7(0));
+        assert_eq!(u9::MIN, u9(0));
+        assert_eq!(u127::MIN, u127(0));
+
+        assert_eq!(i1::MIN, i1(-1));
+        assert_eq!(i2::MIN, i2(-2));
+        assert_eq!(i3::MIN, i3(-4));
+        assert_eq!(i7::MIN, i7(-64));
+        assert_eq!(i9::MIN, i9(-256));
+    }
+
+    #[test]
+    fn test_wrapping_add() {
+        assert_eq!(u1::MAX.wrapping_add(u1(1)), u1(0));
+        assert_eq!(u1::MAX.wrapping_add(u1(0)), u1(1));
+
+        assert_eq!(u5::MAX.wrapping_add(u5(1)), u5(0));
+        assert_eq!(u5::MAX.wrapping_add(u5(4)), u5(3));
+
+        assert_eq!(u127::MAX.wrapping_add(u127(100)), u127(99));
+        assert_eq!(u127::MAX.wrapping_add(u127(1)), u127(0));
+
+        assert_eq!(i1::MAX.wrapping_add(i1(0)), i1(0));
+        assert_eq!(i1::MAX.wrapping_add(i1(-1)), i1(-1));
+
+        assert_eq!(i7::MAX.wrapping_add(i7(1)), i7::MIN);
+        assert_eq!(i7::MAX.wrapping_add(i7(4)), i7(-61));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_overflow_u5() {
+        let _s = u5::MAX + u5(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_overflow_u127() {
+        let _s = u127::MAX + u127(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_overflow_i96() {
+        let _s = i96::MAX + i96(100);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_underflow_i96() {
+        let _s = i96::MIN + i96(-100);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_underflow_i17() {
+        let _s = i17::MIN + i17(-1);
+    }
+
+    #[test]
+    fn test_add() {
+        assert_eq!(u5(1) + u5(2), u5(3));
+
+        assert_eq!(i7::MAX + i7::MIN, i7(-1));
+        assert_eq!(i7(4) + i7(-3), i7(1));
+        assert_eq!(i7(-4) + i7(3), i7(-1));
+        assert_eq!(i7(-3) + i7(-20), i7(-23));
+    }
+
+    #[test]
+    fn test_add_reference_variants() {
+        let (a, b) = (u5::new(10), u5::new(5));
+        assert_eq!(a + b, u5::new(15));
+        assert_eq!(a + &b, u5::new(15));
+        assert_eq!(&a + b, u5::new(15));
+        assert_eq!(&a + &b, u5::new(15));
+        assert_eq!(u12::new(3) + u12::new(4), u12::new(7));
+        assert_eq!(u5(0b1110_0001) + u5(0b1000_0001), u5::new(2));
+        assert_eq!(&i7::new(-30) + &i7::new(-34), i7::MIN);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_add_overflow_message() {
+        let _s = &u5::new(16) + &u5::new(16);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sub_overflow_i23() {
+        let _s = i23::MIN - i23::MAX;
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sub_underflow_u5() {
+        let _s = u5::MIN - u5(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sub_underflow_i5() {
+        let _s = i5::MIN - i5(1);
+    }
+
+    #[test]
+    fn test_sub() {
+        assert_eq!(u5(1) - u5(1), u5(0));
+        assert_eq!(u5(3) - u5(2), u5(1));
+
+        assert_eq!(i1(-1) - i1(-1), i1(0));
+        assert_eq!(i7::MIN - i7::MIN, i7(0));
+        assert_eq!(i7(4) - i7(-3), i7(7));
+        assert_eq!(i7(-4) - i7(3), i7(-7));
+        assert_eq!(i7(-3) - i7(-20), i7(17));
+    }
+
+    #[test]
+    fn test_sub_reference_variants() {
+        let (a, b) = (i7::new(-5), i7::new(3));
+        assert_eq!(a - b, i7::new(-8));
+        assert_eq!(a - &b, i7::new(-8));
+        assert_eq!(&a - b, i7::new(-8));
+        assert_eq!(&a - &b, i7::new(-8));
+        assert_eq!(u5(0b1110_0011) - u5(0b0100_0001), u5::new(2));
+        assert_eq!(u127::MAX - u127::MAX, u127::new(0));
+        assert_eq!(i7::new(-1) - i7::MAX, i7::MIN);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn test_sub_underflow_message() {
+        let _s = &u12::new(3) - &u12::new(4);
+    }
+
+    #[test]
+    fn test_overflowing_sub() {
+        assert_eq!(u5::new(3).overflowing_sub(u5::new(4)), (u5::MAX, true));
+        assert_eq!(u5::new(4).overflowing_sub(u5::new(3)), (u5::new(1), false));
+        assert_eq!(i5::MIN.overflowing_sub(i5::new(1)), (i5::MAX, true));
+        assert_eq!(i5::MAX.overflowing_sub(i5::new(-1)), (i5::MIN, true));
+        assert_eq!(i5::new(-1).overflowing_sub(i5::MIN), (i5::MAX, false));
+        assert_eq!(
+            u127::new(0).overflowing_sub(u127::new(1)),
+            (u127::MAX, true)
+        );
+        assert_eq!(i127::MIN.overflowing_sub(i127::MAX), (i127::new(1), true));
+    }
+
+    #[test]
+    fn test_checked_neg_pow() {
+        assert_eq!(i5::MIN.checked_neg(), None);
+        assert_eq!(i5::MAX.checked_neg(), Some(i5::MIN + i5::new(1)));
+        assert_eq!(i5::new(-3).checked_neg(), Some(i5::new(3)));
+        assert_eq!(i5::new(0).checked_neg(), Some(i5::new(0)));
+        assert_eq!(i1::MIN.checked_neg(), None);
+        assert_eq!(i127::MIN.checked_neg(), None);
+        assert_eq!(i5::MIN.checked_abs(), None);
+
+        assert_eq!(u4::new(3).checked_pow(3), None);
+        assert_eq!(u4::new(3).checked_pow(2), Some(u4::new(9)));
+        assert_eq!(u4::new(2).checked_pow(3), Some(u4::new(8)));
+        assert_eq!(u4::new(2).checked_pow(4), None);
+        assert_eq!(u4::new(0).checked_pow(0), Some(u4::new(1)));
+        assert_eq!(u4::new(1).checked_pow(u32::MAX), Some(u4::new(1)));
+        assert_eq!(u4::new(0).checked_pow(100), Some(u4::new(0)));
+        assert_eq!(u1::new(1).checked_pow(0), Some(u1::new(1)));
+        assert_eq!(i1::new(-1).checked_pow(0), None);
+        assert_eq!(i1::new(-1).checked_pow(1), Some(i1::new(-1)));
+        assert_eq!(i1::new(-1).checked_pow(2), None);
+        assert_eq!(i5::new(-2).checked_pow(4), None);
+        assert_eq!(i5::new(-2).checked_pow(3), Some(i5::new(-8)));
+        assert_eq!(i6::new(-2).checked_pow(5), Some(i6::MIN));
+        assert_eq!(u127::new(2).checked_pow(126), Some(u127::new(1 << 126)));
+        assert_eq!(u127::new(2).checked_pow(127), None);
+
+        for base in 0..16 {
+            for exp in 0..8 {
+                let expected = (base as u32).checked_pow(exp).filter(|&x| x < 16);
+                assert_eq!(
+                    u4::new(base).checked_pow(exp),
+                    expected.map(|x| u4::new(x as u8))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_signed_pow_overflow() {
+        assert_eq!(i5::new(-3).checked_pow(3), None);
+        assert_eq!(i5::new(-3).overflowing_pow(3), (i5::new(5), true));
+        assert_eq!(i5::new(-3).checked_pow(2), Some(i5::new(9)));
+        assert_eq!(i5::new(-3).overflowing_pow(2), (i5::new(9), false));
+        assert_eq!(i5::new(-2).checked_pow(4), None);
+        assert_eq!(i5::new(-2).overflowing_pow(4), (i5::MIN, true));
+        assert_eq!(i5::new(-2).overflowing_pow(3), (i5::new(-8), false));
+        assert_eq!(i5::new(2).overflowing_pow(4), (i5::MIN, true));
+        assert_eq!(i5::MIN.overflowing_pow(1), (i5::MIN, false));
+        assert_eq!(i5::MIN.overflowing_pow(2), (i5::new(0), true));
+        assert_eq!(i6::new(-2).overflowing_pow(5), (i6::MIN, false));
+        assert_eq!(i6::new(-2).overflowing_pow(6), (i6::new(0), true));
+        assert_eq!(i6::new(-1).overflowing_pow(u32::MAX), (i6::new(-1), false));
+        assert_eq!(i1::new(-1).overflowing_pow(0), (i1::new(-1), true));
+        assert_eq!(i127::new(-2).checked_pow(126), None);
+        assert_eq!(i127::new(-2).checked_pow(125), Some(i127::new(-(1 << 125))));
+        assert_eq!(u4::new(3).overflowing_pow(3), (u4::new(11), true));
+
+        macro_rules! check {
+            ($($name:ident),*) => {$(
+                let (min, max) = ($name::MIN.as_i128(), $name::MAX.as_i128());
+                for base in min..=max {
+                    for exp in 0..12 {
+                        let x = $name::new_from(base).unwrap();
+                        let expected = base.pow(exp);
+                        let in_range = expected >= min && expected <= max;
+                        let (wrapped, overflow) = x.overflowing_pow(exp);
+                        assert_eq!(x.checked_pow(exp).is_some(), in_range, "{base}^{exp}");
+                        assert_eq!(overflow, !in_range, "{base}^{exp}");
+                        let modulus = max - min + 1;
+                        let wrapped = wrapped.as_i128().rem_euclid(modulus);
+                        assert_eq!(wrapped, expected.rem_euclid(modulus), "{base}^{exp}");
+                    }
+                }
+            )*};
+        }
+
+        check!(i2, i4, i5, i7, u4);
+    }
+
+    #[test]
+    fn test_pow() {
+        assert_eq!(u4::new(2).pow(3), u4::new(8));
+        assert_eq!(u4::new(3).pow(2), u4::new(9));
+        assert_eq!(u4::new(0).pow(0), u4::new(1));
+        assert_eq!(u4::new(1).pow(u32::MAX), u4::new(1));
+        assert_eq!(u4(0b1111_0010).pow(3), u4::new(8));
+        assert_eq!(i5::new(-2).pow(3), i5::new(-8));
+        assert_eq!(i5::new(-3).pow(2), i5::new(9));
+        assert_eq!(i6::new(-2).pow(5), i6::MIN);
+        assert_eq!(i6::new(-1).pow(u32::MAX - 1), i6::new(1));
+        assert_eq!(u127::new(3).pow(80), u127::new(3u128.pow(80)));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn test_pow_overflow() {
+        let _ = u4::new(2).pow(4);
+    }
+
+    #[cfg(not(debug_assertions))]
+    #[test]
+    fn test_pow_wraps_in_release() {
+        assert_eq!(u4::new(2).pow(4), u4::new(0));
+        assert_eq!(i5::new(-3).pow(3), i5::new(5));
+    }
+
+    #[test]
+    fn test_saturating_pow_abs() {
+        assert_eq!(i4::MIN.saturating_abs(), i4::MAX);
+        assert_eq!(i4::new(-7).saturating_abs(), i4::new(7));
+        assert_eq!(u3::new(3).saturating_pow(4), u3::MAX);
+        assert_eq!(u3::new(2).saturating_pow(2), u3::new(4));
+        assert_eq!(u3::new(0).saturating_pow(0), u3::new(1));
+        assert_eq!(i4::new(-2).saturating_pow(3), i4::MIN);
+        assert_eq!(i4::new(-3).saturating_pow(3), i4::MIN);
+        assert_eq!(i4::new(-3).saturating_pow(2), i4::MAX);
+        assert_eq!(i4::new(-2).saturating_pow(2), i4::new(4));
+        assert_eq!(i1::new(-1).saturating_pow(0), i1::MAX);
+        assert_eq!(u127::new(3).saturating_pow(100), u127::MAX);
+    }
+
+    #[test]
+    fn test_mul_primitive() {
+        assert_eq!(u7::new(10).checked_mul_primitive(12), Some(u7::new(120)));
+        assert_eq!(u7::new(10).checked_mul_primitive(13), None);
+        assert_eq!(u7::new(10).checked_mul_primitive(30), None);
+        assert_eq!(u7::new(10).wrapping_mul_primitive(30), u7::new(44));
+        assert_eq!(u7::new(10).wrapping_mul_primitive(12), u7::new(120));
+        assert_eq!(
+            u7(0b1000_0010).checked_mul_primitive(60),
+            Some(u7::new(120))
+        );
+        assert_eq!(u63::MAX.wrapping_mul_primitive(u64::MAX), u63::new(1));
+        assert_eq!(u127::new(1 << 100).checked_mul_primitive(1 << 27), None);
+
+        assert_eq!(i7::new(-8).checked_mul_primitive(8), Some(i7::MIN));
+        assert_eq!(i7::new(-8).checked_mul_primitive(-8), None);
+        assert_eq!(i7::new(-8).wrapping_mul_primitive(-8), i7::MIN);
+        assert_eq!(i7::new(5).checked_mul_primitive(-100), None);
+        assert_eq!(i7::new(5).wrapping_mul_primitive(-100), i7::new(12));
+    }
+
+    #[test]
+    fn test_mul() {
+        let (a, b) = (u6::new(8), u6::new(7));
+        assert_eq!(a * b, u6::new(56));
+        assert_eq!(a * &b, u6::new(56));
+        assert_eq!(&a * b, u6::new(56));
+        assert_eq!(&a * &b, u6::new(56));
+        assert_eq!(u7::new(9) * u7::new(14), u7::new(126));
+        assert_eq!(u5(0b1110_0011) * u5::new(10), u5::new(30));
+        assert_eq!(i7::new(-8) * i7::new(8), i7::MIN);
+        assert_eq!(i7::new(-9) * i7::new(-7), i7::MAX);
+        assert_eq!(u127::new(1 << 63) * u127::new(1 << 63), u127::new(1 << 126));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn test_mul_overflow() {
+        let _p = u4::new(15) * u4::new(15);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn test_mul_container_overflow() {
+        // 127 * 127 overflows the backing u8 as well as the u7 range.
+        let _p = u7::MAX * u7::MAX;
+    }
+
+    #[test]
+    fn test_add_sub_64_bit_backed_boundaries() {
+        fn wrap_u63(x: i128) -> u63 {
+            u63::new(x.rem_euclid(1 << 63) as u64)
+        }
+        fn wrap_i63(x: i128) -> i63 {
+            let x = x.rem_euclid(1 << 63);
+            i63::new((if x >= 1 << 62 { x - (1 << 63) } else { x }) as i64)
+        }
+
+        let unsigned = [
+            0,
+            1,
+            2,
+            (1 << 62) - 1,
+            1 << 62,
+            (1 << 63) - 2,
+            (1 << 63) - 1,
+        ];
+        for &a in &unsigned {
+            for &b in &unsigned {
+                let (x, y) = (u63::new(a as u64), u63::new(b as u64));
+                let (sum, difference) = (a + b, a - b);
+                assert_eq!(x.wrapping_add(y), wrap_u63(sum));
+                assert_eq!(x.wrapping_sub(y), wrap_u63(difference));
+                assert_eq!(x.overflowing_add(y), (wrap_u63(sum), sum >= 1 << 63));
+                assert_eq!(x.overflowing_sub(y), (wrap_u63(difference), difference < 0));
+                assert_eq!(x.checked_add(y).is_some(), sum < 1 << 63);
+            }
+        }
+
+        let signed = [
+            -(1 << 62),
+            -(1 << 62) + 1,
+            -2,
+            -1,
+            0,
+            1,
+            (1 << 62) - 2,
+            (1 << 62) - 1,
+        ];
+        for &a in &signed {
+            for &b in &signed {
+                let (x, y) = (i63::new(a as i64), i63::new(b as i64));
+                let (sum, difference) = (a + b, a - b);
+                let in_range = |v: i128| (-(1 << 62)..1 << 62).contains(&v);
+                assert_eq!(x.wrapping_add(y), wrap_i63(sum));
+                assert_eq!(x.wrapping_sub(y), wrap_i63(difference));
+                assert_eq!(x.overflowing_add(y), (wrap_i63(sum), !in_range(sum)));
+                assert_eq!(
+                    x.overflowing_sub(y),
+                    (wrap_i63(difference), !in_range(difference))
+                );
+                assert_eq!(x.checked_add(y).is_some(), in_range(sum));
+            }
+        }
+
+        assert_eq!(u33::MAX.wrapping_add(u33::new(1)), u33::new(0));
+        assert_eq!(i33::MIN.wrapping_sub(i33::new(1)), i33::MAX);
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let (a, b) = (u10::new(100), u10::new(7));
+        assert_eq!(a / b, u10::new(14));
+        assert_eq!(a % b, u10::new(2));
+        assert_eq!(&a / b, u10::new(14));
+        assert_eq!(a % &b, u10::new(2));
+        assert_eq!(&a / &b, u10::new(14));
+        assert_eq!(&a % &b, u10::new(2));
+        assert_eq!(u10(0b1100_0110_0100) / u10::new(7), u10::new(14));
+        assert_eq!(i10::new(-100) / i10::new(7), i10::new(-14));
+        assert_eq!(i10::new(-100) % i10::new(7), i10::new(-2));
+        assert_eq!(i10::MIN / i10::new(-2), i10::new(256));
+        assert_eq!(i10::MIN % i10::new(-2), i10::new(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide with overflow")]
+    fn test_div_min_by_minus_one() {
+        let _q = i10::MIN / i10::new(-1);
+    }
+
+    #[test]
+    fn test_neg() {
+        assert_eq!(-i5::new(10), i5::new(-10));
+        assert_eq!(-&i5::new(-10), i5::new(10));
+        assert_eq!(-i5::MAX, i5::MIN + i5::new(1));
+        assert_eq!(-i5::new(0), i5::new(0));
+        assert_eq!(-i5(0b1110_0011u8 as i8), i5::new(-3));
+        assert_eq!(-i1::new(0), i1::new(0));
+        assert_eq!(-i127::MAX, i127::new(-i127::MAX.0));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "attempt to negate with overflow")]
+    fn test_neg_min() {
+        let _n = -i5::MIN;
+    }
+
+    /// Checks the checked, wrapping, overflowing and saturating arithmetic of `$name` on
+    /// the operands `$a` and `$b`, which must be in range, against the same operations on
+    /// `i128`.
+    ///
+    /// The operands are plain integers, so this can serve as the body of a fuzz target.
+    macro_rules! check_arithmetic_against_wide_reference {
+        ($name:ident, $a:expr, $b:expr) => {{
+            let (a, b): (i128, i128) = ($a, $b);
+            let (min, max) = ($name::MIN.as_i128(), $name::MAX.as_i128());
+            let in_range = |x: Option<i128>| x.filter(|&x| x >= min && x <= max);
+            let wrap = |x: i128| {
+                let field = u128::MAX >> (128 - $name::BITS);
+                let low = x as u128 & field;
+                if min < 0 && low >> ($name::BITS - 1) == 1 {
+                    (low | !field) as i128
+                } else {
+                    low as i128
+                }
+            };
+            let overflowing = |x: Option<i128>, wrapped: i128| (wrapped, in_range(x).is_none());
+            let wide = |x: Option<$name>| x.map(|x| x.as_i128());
+            let wide_pair = |(x, overflow): ($name, bool)| (x.as_i128(), overflow);
+
+            let (x, y) = ($name::new_from(a).unwrap(), $name::new_from(b).unwrap());
+            let context = (stringify!($name), a, b);
+
+            assert_eq!(
+                wide(x.checked_add(y)),
+                in_range(a.checked_add(b)),
+                "{context:?}"
+            );
+            assert_eq!(
+                wide(x.checked_sub(y)),
+                in_range(a.checked_sub(b)),
+                "{context:?}"
+            );
+            assert_eq!(
+                wide(x.checked_mul(y)),
+                in_range(a.checked_mul(b)),
+                "{context:?}"
+            );
+
+            assert_eq!(
+                x.wrapping_add(y).as_i128(),
+                wrap(a.wrapping_add(b)),
+                "{context:?}"
+            );
+            assert_eq!(
+                x.wrapping_sub(y).as_i128(),
+                wrap(a.wrapping_sub(b)),
+                "{context:?}"
+            );
+            assert_eq!(
+                x.wrapping_mul(y).as_i128(),
+                wrap(a.wrapping_mul(b)),
+                "{context:?}"
+            );
+
+            assert_eq!(
+                wide_pair(x.overflowing_add(y)),
+                overflowing(a.checked_add(b), wrap(a.wrapping_add(b))),
+                "{context:?}"
+            );
+            assert_eq!(
+                wide_pair(x.overflowing_sub(y)),
+                overflowing(a.checked_sub(b), wrap(a.wrapping_sub(b))),
+                "{context:?}"
+            );
+            assert_eq!(
+                wide_pair(x.overflowing_mul(y)),
+                overflowing(a.checked_mul(b), wrap(a.wrapping_mul(b))),
+                "{context:?}"
+            );
+
+            let saturate = |x: i128| x.clamp(min, max);
+            assert_eq!(
+                x.saturating_add(y).as_i128(),
+                saturate(a.saturating_add(b)),
+                "{context:?}"
+            );
+            assert_eq!(
+                x.saturating_sub(y).as_i128(),
+                saturate(a.saturating_sub(b)),
+                "{context:?}"
+            );
+            assert_eq!(
+                x.saturating_mul(y).as_i128(),
+                saturate(a.saturating_mul(b)),
+                "{context:?}"
+            );
+
+            assert_eq!(
+                wide(x.checked_div(y)),
+                in_range(a.checked_div(b)),
+                "{context:?}"
+            );
+            if b != 0 {
+                assert_eq!(
+                    wide(x.checked_rem(y)),
+                    in_range(a.checked_div(b)).map(|_| a % b),
+                    "{context:?}"
+                );
+                assert_eq!(x.wrapping_div(y).as_i128(), wrap(a / b), "{context:?}");
+                assert_eq!(x.wrapping_rem(y).as_i128(), wrap(a % b), "{context:?}");
+                assert_eq!(
+                    wide_pair(x.overflowing_div(y)),
+                    overflowing(Some(a / b), wrap(a / b)),
+                    "{context:?}"
+                );
+            } else {
+                assert_eq!(x.checked_rem(y), None, "{context:?}");
+            }
+        }};
+    }
+
+    #[test]
+    fn test_arithmetic_against_wide_reference() {
+        fn samples(min: i128, max: i128) -> impl Iterator<Item = i128> {
+            let random = xorshift().take(64).map(move |x| {
+                let span = (max - min) as u128 + 1;
+                min + (((x as u128) << 64 | x as u128) % span) as i128
+            });
+            [
+                min,
+                min + 1,
+                -1,
+                0,
+                1,
+                2,
+                max / 2,
+                max / 2 + 1,
+                max - 1,
+                max,
+            ]
+            .into_iter()
+            .filter(move |&x| x >= min && x <= max)
+            .chain(random)
+        }
+
+        macro_rules! check {
+            ($($name:ident),*) => {$(
+                let (min, max) = ($name::MIN.as_i128(), $name::MAX.as_i128());
+                for a in samples(min, max) {
+                    for b in samples(min, max) {
+                        check_arithmetic_against_wide_reference!($name, a, b);
+                    }
+                }
+            )*};
+        }
+
+        check!(u1, u5, u7, u9, u15, u17, u31, u33, u63, u65, u127);
+        check!(i1, i5, i7, i9, i15, i17, i31, i33, i63, i65, i127);
+    }
+
+    #[test]
+    fn test_overflowing_family() {
+        assert_eq!(u5::MAX.overflowing_add(u5::new(1)), (u5::new(0), true));
+        assert_eq!(u5::new(30).overflowing_add(u5::new(1)), (u5::MAX, false));
+        assert_eq!(u5::new(0).overflowing_sub(u5::new(1)), (u5::MAX, true));
+        assert_eq!(u5::new(8).overflowing_mul(u5::new(4)), (u5::new(0), true));
+        assert_eq!(u5::new(7).overflowing_mul(u5::new(4)), (u5::new(28), false));
+        assert_eq!(u5::new(31).overflowing_div(u5::new(4)), (u5::new(7), false));
+        assert_eq!(u5::new(0).overflowing_neg(), (u5::new(0), false));
+        assert_eq!(u5::new(1).overflowing_neg(), (u5::MAX, true));
+        assert_eq!(u5(0b1110_0000).overflowing_neg(), (u5::new(0), false));
+
+        assert_eq!(i5::MAX.overflowing_add(i5::new(1)), (i5::MIN, true));
+        assert_eq!(i5::MIN.overflowing_sub(i5::new(1)), (i5::MAX, true));
+        assert_eq!(i5::new(-4).overflowing_mul(i5::new(-4)), (i5::MIN, true));
+        assert_eq!(i5::MIN.overflowing_div(i5::new(-1)), (i5::MIN, true));
+        assert_eq!(i5::MIN.overflowing_div(i5::new(2)), (i5::new(-8), false));
+        assert_eq!(i5::MIN.overflowing_neg(), (i5::MIN, true));
+        assert_eq!(i5::MAX.overflowing_neg(), (i5::new(-15), false));
+        assert_eq!(i127::MIN.overflowing_div(i127::new(-1)), (i127::MIN, true));
+        assert_eq!(u127::new(1).overflowing_neg(), (u127::MAX, true));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn test_overflowing_div_by_zero() {
+        let _ = u5::new(1).overflowing_div(u5::new(0));
+    }
+
+    #[test]
+    fn test_wrapping_mul_div_rem() {
+        assert_eq!(u4::new(15).wrapping_mul(u4::new(2)), u4::new(14));
+        assert_eq!(u4::new(3).wrapping_mul(u4::new(5)), u4::new(15));
+        assert_eq!(u4(0b1111_0011).wrapping_mul(u4::new(5)), u4::new(15));
+        assert_eq!(i5::new(-4).wrapping_mul(i5::new(4)), i5::MIN);
+        assert_eq!(i5::new(-4).wrapping_mul(i5::new(-4)), i5::MIN);
+        assert_eq!(i5::MAX.wrapping_mul(i5::new(2)), i5::new(-2));
+        assert_eq!(u127::MAX.wrapping_mul(u127::MAX), u127::new(1));
+
+        assert_eq!(u4::new(15).wrapping_div(u4::new(4)), u4::new(3));
+        assert_eq!(u4(0b1111_1111).wrapping_div(u4::new(4)), u4::new(3));
+        assert_eq!(i5::MIN.wrapping_div(i5::new(-1)), i5::MIN);
+        assert_eq!(i5::new(-15).wrapping_div(i5::new(4)), i5::new(-3));
+        assert_eq!(i127::MIN.wrapping_div(i127::new(-1)), i127::MIN);
+
+        assert_eq!(u4::new(15).wrapping_rem(u4::new(4)), u4::new(3));
+        assert_eq!(i5::MIN.wrapping_rem(i5::new(-1)), i5::new(0));
+        assert_eq!(i5::new(-15).wrapping_rem(i5::new(4)), i5::new(-3));
+        assert_eq!(i127::MIN.wrapping_rem(i127::new(-1)), i127::new(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn test_wrapping_div_by_zero() {
+        let _ = u5::new(1).wrapping_div(u5::new(0));
+    }
+
+    #[test]
+    fn test_wrapping_neg_abs() {
+        assert_eq!(i5::MIN.wrapping_neg(), i5::MIN);
+        assert_eq!(i5::MAX.wrapping_neg(), i5::new(-15));
+        assert_eq!(i5::new(-3).wrapping_neg(), i5::new(3));
+        assert_eq!(i5::new(0).wrapping_neg(), i5::new(0));
+        assert_eq!(i5(0b0111_0000).wrapping_neg(), i5::MIN);
+        assert_eq!(i127::MIN.wrapping_neg(), i127::MIN);
+
+        assert_eq!(u5::new(0).wrapping_neg(), u5::new(0));
+        assert_eq!(u5::new(1).wrapping_neg(), u5::MAX);
+        assert_eq!(u5::new(20).wrapping_neg(), u5::new(12));
+        assert_eq!(u5(0b1110_0001).wrapping_neg(), u5::MAX);
+        assert_eq!(u127::new(1).wrapping_neg(), u127::MAX);
+
+        assert_eq!(i5::new(-3).wrapping_abs(), i5::new(3));
+        assert_eq!(i5::MIN.wrapping_abs(), i5::MIN);
+        assert_eq!(i5(0b0111_1101).wrapping_abs(), i5::new(3));
+    }
+
+    #[test]
+    fn test_signed_min_edge_cases() {
+        macro_rules! check {
+            ($($name:ident),*) => {$(
+                let name = stringify!($name);
+                assert_eq!($name::MIN.wrapping_neg(), $name::MIN, "{name}");
+                assert_eq!($name::MIN.wrapping_abs(), $name::MIN, "{name}");
+                assert_eq!($name::MIN.unsigned_abs().as_u128(), 1 << ($name::BITS - 1), "{name}");
+                assert_eq!($name::MIN.checked_abs(), None, "{name}");
+                assert_eq!($name::MIN.checked_neg(), None, "{name}");
+            )*};
+        }
+
+        check!(
+            i1, i2, i3, i4, i5, i6, i7, i9, i10, i11, i12, i13, i14, i15, i17, i18, i19, i20, i21,
+            i22, i23, i24, i25, i26, i27, i28, i29, i30, i31, i33, i34, i35, i36, i37, i38, i39,
+            i40, i41, i42, i43, i44, i45, i46, i47, i48, i49, i50, i51, i52, i53, i54, i55, i56,
+            i57, i58, i59, i60, i61, i62, i63, i65, i66, i67, i68, i69, i70, i71, i72, i73, i74,
+            i75, i76, i77, i78, i79, i80, i81, i82, i83, i84, i85, i86, i87, i88, i89, i90, i91,
+            i92, i93, i94, i95, i96, i97, i98, i99, i100, i101, i102, i103, i104, i105, i106, i107,
+            i108, i109, i110, i111, i112, i113, i114, i115, i116, i117, i118, i119, i120, i121,
+            i122, i123, i124, i125, i126, i127
+        );
+    }
+
+    #[test]
+    fn test_checked_add_sub() {
+        assert_eq!(u5::MAX.checked_add(u5::new(1)), None);
+        assert_eq!(u5::new(3).checked_add(u5::new(4)), Some(u5::new(7)));
+        assert_eq!(u5::new(16).checked_add(u5::new(15)), Some(u5::MAX));
+        assert_eq!(u5::new(16).checked_add(u5::new(16)), None);
+        assert_eq!(u5::new(7).checked_sub(u5::new(4)), Some(u5::new(3)));
+        assert_eq!(u5::new(3).checked_sub(u5::new(4)), None);
+        assert_eq!(
+            u5(0b1110_0011).checked_add(u5(0b0010_0100)),
+            Some(u5::new(7))
+        );
+
+        assert_eq!(i5::MAX.checked_add(i5::new(1)), None);
+        assert_eq!(i5::new(-3).checked_add(i5::new(4)), Some(i5::new(1)));
+        assert_eq!(i5::new(-3).checked_sub(i5::new(13)), Some(i5::MIN));
+        assert_eq!(i5::new(-3).checked_sub(i5::new(14)), None);
+
+        assert_eq!(u127::MAX.checked_add(u127::new(1)), None);
+        assert_eq!(u127::new(0).checked_sub(u127::new(1)), None);
+        assert_eq!(i127::MAX.checked_add(i127::MIN), Some(i127::new(-1)));
+    }
+
+    #[test]
+    fn test_saturating_arithmetic() {
+        assert_eq!(u5::MAX.saturating_add(u5::new(10)), u5::MAX);
+        assert_eq!(u5::new(3).saturating_add(u5::new(10)), u5::new(13));
+        assert_eq!(u5::new(3).saturating_sub(u5::new(10)), u5::MIN);
+        assert_eq!(u5::new(13).saturating_sub(u5::new(10)), u5::new(3));
+        assert_eq!(u5::new(8).saturating_mul(u5::new(4)), u5::MAX);
+        assert_eq!(u5::new(7).saturating_mul(u5::new(4)), u5::new(28));
+        assert_eq!(u7::MAX.saturating_mul(u7::MAX), u7::MAX);
+        assert_eq!(u127::MAX.saturating_mul(u127::new(2)), u127::MAX);
+
+        assert_eq!(i5::MIN.saturating_sub(i5::new(5)), i5::MIN);
+        assert_eq!(i5::MAX.saturating_sub(i5::new(-5)), i5::MAX);
+        assert_eq!(i5::MAX.saturating_add(i5::new(5)), i5::MAX);
+        assert_eq!(i5::MIN.saturating_add(i5::new(-5)), i5::MIN);
+        assert_eq!(i5::new(-3).saturating_add(i5::new(5)), i5::new(2));
+        assert_eq!(i5::new(4).saturating_mul(i5::new(4)), i5::MAX);
+        assert_eq!(i5::new(-4).saturating_mul(i5::new(-4)), i5::MAX);
+        assert_eq!(i5::new(-4).saturating_mul(i5::new(5)), i5::MIN);
+        assert_eq!(i5::new(5).saturating_mul(i5::new(-4)), i5::MIN);
+        assert_eq!(i5::new(-4).saturating_mul(i5::new(4)), i5::MIN);
+        assert_eq!(i5::MIN.saturating_mul(i5::new(-1)), i5::MAX);
+        assert_eq!(i5::new(-3).saturating_mul(i5::new(5)), i5::new(-15));
+        assert_eq!(i127::MIN.saturating_mul(i127::new(2)), i127::MIN);
+    }
+
+    #[test]
+    fn test_asymmetric_signed_range() {
+        macro_rules! check {
+            ($($name:ident),*) => {$(
+                let (one, zero) = ($name::new(1), $name::new(0));
+
+                assert_eq!($name::MIN.checked_add($name::MAX), Some($name::new(-1)));
+                assert_eq!($name::MAX.checked_add(one), None);
+                assert_eq!($name::MIN.checked_add(-one), None);
+                assert_eq!(($name::MIN + one).checked_sub(one), Some($name::MIN));
+                assert_eq!($name::new(-1).checked_sub($name::MAX), Some($name::MIN));
+                assert_eq!(zero.checked_sub($name::MAX), Some($name::MIN + one));
+                assert_eq!(zero.checked_sub($name::MIN), None);
+                assert_eq!($name::new(-1).checked_sub($name::MIN), Some($name::MAX));
+                assert_eq!($name::MIN.checked_sub(one), None);
+                assert_eq!($name::MIN.checked_abs(), None);
+
+                assert_eq!($name::MAX.saturating_add(one), $name::MAX);
+                assert_eq!($name::MIN.saturating_add(-one), $name::MIN);
+                assert_eq!($name::MIN.saturating_add($name::MAX), $name::new(-1));
+                assert_eq!(zero.saturating_sub($name::MIN), $name::MAX);
+                assert_eq!($name::new(-1).saturating_sub($name::MIN), $name::MAX);
+                assert_eq!($name::new(-2).saturating_sub($name::MAX), $name::MIN);
+                assert_eq!($name::new(-1).saturating_sub($name::MAX), $name::MIN);
+                assert_eq!($name::MIN.saturating_sub(one), $name::MIN);
+                assert_eq!($name::MAX.saturating_sub(-one), $name::MAX);
+            )*};
+        }
+
+        check!(i2, i5, i7, i9, i17, i31, i33, i63, i65, i127);
+
+        assert_eq!(i1::MIN.checked_sub(i1::MIN), Some(i1::new(0)));
+        assert_eq!(i1::new(0).checked_sub(i1::MIN), None);
+        assert_eq!(i1::new(0).saturating_sub(i1::MIN), i1::MAX);
+        assert_eq!(i1::MIN.saturating_add(i1::MIN), i1::MIN);
+
+        assert_eq!(u5::new(0).saturating_sub(u5::new(1)), u5::MIN);
+        assert_eq!(u5::MAX.saturating_add(u5::new(1)), u5::MAX);
+        assert_eq!(u5::new(0).checked_sub(u5::new(1)), None);
+    }
+
+    #[test]
+    fn test_arithmetic_assign() {
+        let mut x = u12::new(5);
+        x += u12::new(3);
+        assert_eq!(x, u12::new(8));
+        x -= u12::new(2);
+        assert_eq!(x, u12::new(6));
+        x *= u12::new(7);
+        assert_eq!(x, u12::new(42));
+        x /= u12::new(5);
+        assert_eq!(x, u12::new(8));
+        x %= u12::new(5);
+        assert_eq!(x, u12::new(3));
+
+        let mut y = i7(0b0111_1101);
+        y += i7::new(1);
+        assert_eq!(y, i7::new(-2));
+        y *= i7::new(16);
+        assert_eq!(y, i7::new(-32));
+        y /= i7::new(-5);
+        assert_eq!(y, i7::new(6));
+    }
+
+    #[cfg(not(debug_assertions))]
+    #[test]
+    fn test_arithmetic_assign_wraps_in_release() {
+        let mut x = u12::MAX;
+        x += u12::new(2);
+        assert_eq!(x, u12::new(1));
+        x -= u12::new(2);
+        assert_eq!(x, u12::MAX);
+        x *= u12::new(2);
+        assert_eq!(x, u12::MAX - u12::new(1));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_add_assign_overflow() {
+        let mut x = u12::MAX;
+        x += u12::new(1);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn test_mul_assign_overflow() {
+        let mut x = i7::new(-8);
+        x *= i7::new(-8);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn test_div_assign_by_zero() {
+        let mut x = u12::new(1);
+        x /= u12::new(0);
+    }
+
+    #[test]
+    fn test_shr() {
+        assert_eq!(u5(8) >> 1usize, u5(4));
+        assert_eq!(u5(8) >> 1u8, u5(4));
+        assert_eq!(u5(8) >> 1u16, u5(4));
+        assert_eq!(u5(8) >> 1u32, u5(4));
+        assert_eq!(u5(8) >> 1u64, u5(4));
+        assert_eq!(u5(8) >> 1isize, u5(4));
+        assert_eq!(u5(8) >> 1i8, u5(4));
+        assert_eq!(u5(8) >> 1i16, u5(4));
+        assert_eq!(u5(8) >> 1i32, u5(4));
+        assert_eq!(u5(8) >> 1i64, u5(4));
+
+        assert_eq!(u5::MAX >> 4, u5(1));
+
+        assert_eq!(i7(-1) >> 5, i7(-1));
+    }
+
+    #[test]
+    fn test_shl() {
+        assert_eq!(u5(16) << 1usize, u5(32));
+        assert_eq!(u5(16) << 1u8, u5(32));
+        assert_eq!(u5(16) << 1u16, u5(32));
+        assert_eq!(u5(16) << 1u32, u5(32));
+        assert_eq!(u5(16) << 1u64, u5(32));
+        assert_eq!(u5(16) << 1isize, u5(32));
+        assert_eq!(u5(16) << 1i8, u5(32));
+        assert_eq!(u5(16) << 1i16, u5(32));
+        assert_eq!(u5(16) << 1i32, u5(32));
+        assert_eq!(u5(16) << 1i64, u5(32));
+
+        assert_eq!(u5::MAX << 4, u5(16));
+
+        assert_eq!(i5(16) << 1, i5(0));
+        assert_eq!(i7(1) << 3, i7(8));
+    }
+
+    #[test]
+    fn test_shr_assign() {
+        let mut x = u10(512);
+        x >>= 1usize;
+        assert_eq!(x, u10(256));
+        x >>= 1isize;
+        assert_eq!(x, u10(128));
+        x >>= 1u8;
+        assert_eq!(x, u10(64));
+        x >>= 1i8;
+        assert_eq!(x, u10(32));
+        x >>= 2u64;
+        assert_eq!(x, u10(8));
+        x >>= 3i32;
+        assert_eq!(x, u10(1));
+    }
+
+    #[test]
+    fn test_shl_assign() {
+        let mut x = u9(1);
+        x <<= 3i32;
+        assert_eq!(x, u9(8));
+        x <<= 2u64;
+        assert_eq!(x, u9(32));
+        x <<= 1usize;
+        assert_eq!(x, u9(64));
+        x <<= 1isize;
+        assert_eq!(x, u9(128));
+        x <<= 1u8;
+        assert_eq!(x, u9(256));
+    }
+
+    #[test]
+    fn test_bitor() {
+        assert_eq!(u9(1) | u9(8), u9(9));
+        assert_eq!(&u9(1) | u9(8), u9(9));
+        assert_eq!(u9(1) | &u9(8), u9(9));
+        assert_eq!(&u9(1) | &u9(8), u9(9));
+    }
+
+    #[test]
+    fn test_bitor_assign() {
+        let mut x = u12(4);
+        x |= u12(1);
+        assert_eq!(x, u12(5));
+        x |= u12(128);
+        assert_eq!(x, u12(133));
+        x = u12(1);
+        x |= u12(127);
+        assert_eq!(x, u12(127));
+    }
+
+    #[test]
+    fn test_bitxor() {
+        assert_eq!(u7(0x7F) ^ u7(42), u7(85));
+        assert_eq!(&u7(0) ^ u7(42), u7(42));
+        assert_eq!(u7(0x10) ^ &u7(0x1), u7(0x11));
+        assert_eq!(&u7(11) ^ &u7(1), u7(10));
+    }
+
+    #[test]
+    fn test_bitxor_assign() {
+        let mut x = u12(4);
+        x ^= u12(1);
+        assert_eq!(x, u12(5));
+        x ^= u12(128);
+        assert_eq!(x, u12(133));
+        x ^= u12(1);
+        assert_eq!(x, u12(132));
+        x ^= u12(127);
+        assert_eq!(x, u12(251));
+    }
+
+    #[test]
+    fn test_bitand() {
+        assert_eq!(i9(-7) & i9(-9), i9::from(-7i8 & -9i8));
+        assert_eq!(&i9(-7) & i9(-9), i9::from(&-7i8 & -9i8));
+        assert_eq!(i9(-7) & &i9(-9), i9::from(-7i8 & &-9i8));
+        assert_eq!(&i9(-7) & &i9(-9), i9::from(&-7i8 & &-9i8));
+
+        assert_eq!(u9(8) & u9(9), u9(8));
+        assert_eq!(&u9(8) & u9(9), u9(8));
+        assert_eq!(u9(8) & &u9(9), u9(8));
+        assert_eq!(&u9(8) & &u9(9), u9(8));
+    }
+
+    #[test]
+    fn test_bitand_bitxor_masking() {
+        let (a, b) = (u9::new(0b1100), u9::new(0b1010));
+        assert_eq!(a & b, u9::new(0b1000));
+        assert_eq!(a ^ b, u9::new(0b0110));
+        assert_eq!(&a & &b, u9::new(0b1000));
+        assert_eq!(&a ^ &b, u9::new(0b0110));
+
+        let (a, b) = (u9(0xfe0c), u9(0xfc0a));
+        assert_eq!((a & b).0, 0b1000);
+        assert_eq!((a ^ b).0, 0b0110);
+        assert_eq!((&a & b).0, 0b1000);
+        assert_eq!((a ^ &b).0, 0b0110);
+
+        assert_eq!(i9::new(-1) & i9::new(-256), i9::MIN);
+        assert_eq!(i9::new(-1) ^ i9::MIN, i9::MAX);
+        assert_eq!((i9(0x0100) ^ i9(0x0000)).0, -256);
+    }
+
+    #[test]
+    fn test_bitand_bitxor_assign_masking() {
+        let mut x = u13::new(0b1_0110_1100_1011);
+        x &= u13::new(0b1_1111_0000_1111);
+        assert_eq!(x, u13::new(0b1_0110_0000_1011));
+        x &= u13(0xe000 | 0b0_0110_0000_0001);
+        assert_eq!(x.0, 0b0_0110_0000_0001);
+
+        let mut x = u13(0xe000 | 0b1_0000_0000_0001);
+        x ^= u13::new(0b0_0000_0000_0011);
+        assert_eq!(x.0, 0b1_0000_0000_0010);
+        x ^= u13(0xe000 | 0b1_0000_0000_0010);
+        assert_eq!(x.0, 0);
+        x ^= u13::MAX;
+        assert_eq!(x.0, 0x1fff);
+    }
+
+    #[test]
+    fn test_bitand_assign() {
+        let mut x = u12(255);
+        x &= u12(127);
+        assert_eq!(x, u12(127));
+        x &= u12(7);
+        assert_eq!(x, u12(7));
+        x &= u12(127);
+        assert_eq!(x, u12(7));
+        x &= u12(4);
+        assert_eq!(x, u12(4));
+    }
+
+    #[test]
+    fn test_bit_ops_primitive_rhs() {
+        assert_eq!(u12::new(0xf00) & 0x0ffu16, u12::new(0));
+        assert_eq!(u12::new(0xf0f) & 0xf0ffu16, u12::new(0x00f));
+        assert_eq!(u12::new(0xf00) | 0x0ffu16, u12::MAX);
+        assert_eq!(u12::new(0x00f) | 0xf000u16, u12::new(0x00f));
+        assert_eq!(u12::new(0xff0) ^ 0x0ffu16, u12::new(0xf0f));
+        assert_eq!(u12::new(0xff0) ^ 0xffffu16, u12::new(0x00f));
+        assert_eq!(u12(0xf000) | 0x001u16, u12::new(0x001));
+
+        assert_eq!(i6::new(-1) & 0b0_0011i8, i6::new(3));
+        assert_eq!(i6::new(-8) & -1i8, i6::new(-8));
+        assert_eq!(i6::new(0) | 0b10_0000i8, i6::MIN);
+        assert_eq!(i6::new(0) | 0b0110_0001i8, i6::MIN + i6::new(1));
+        assert_eq!(i6::new(-1) ^ 0b01_1111i8, i6::MIN);
+        assert_eq!(i6::new(5) ^ 0b1100_0000u8 as i8, i6::new(5));
+
+        assert_eq!(u127::MAX & u128::MAX, u127::MAX);
+
+        let x = u12::new(0xff0);
+        assert_eq!(&x & 0xf0ffu16, u12::new(0x0f0));
+        assert_eq!(&x | 0xf00fu16, u12::MAX);
+        assert_eq!(&x ^ 0xffffu16, u12::new(0x00f));
+        assert_eq!(&i6::new(-1) & 0b1100_0011u8 as i8, i6::new(3));
+
+        let mut x = u12::new(0xff0);
+        x &= 0xf0ffu16;
+        assert_eq!(x, u12::new(0x0f0));
+        x |= 0xf00fu16;
+        assert_eq!(x, u12::new(0x0ff));
+        x ^= 0xffffu16;
+        assert_eq!(x, u12::new(0xf00));
+
+        let mut x = u12(0xf000);
+        x |= 0x001u16;
+        assert_eq!(x, u12::new(0x001));
+
+        let mut x = i6::new(0);
+        x |= 0b0110_0001i8;
+        assert_eq!(x, i6::MIN + i6::new(1));
+        x ^= -1i8;
+        assert_eq!(x, i6::new(30));
+        x &= 0b0000_1111i8;
+        assert_eq!(x, i6::new(14));
+    }
+
+    #[test]
+    fn test_bit_range() {
+        assert_eq!(u12::new(0b1111_0000_1010).bit_range(4, 8), u12::new(0));
+        assert_eq!(u12::new(0b1111_0110_1010).bit_range(4, 8), u12::new(0b0110));
+        assert_eq!(
+            u12::new(0b1111_0110_1010).bit_range(2, 10),
+            u12::new(0b1101_1010)
+        );
+        assert_eq!(u12::new(0b1111_0110_1010).bit_range(5, 5), u12::new(0));
+
+        assert_eq!(u12::MAX.bit_range(0, 12), u12::MAX);
+        assert_eq!(u127::MAX.bit_range(0, 127), u127::MAX);
+
+        assert_eq!(i6::new(-1).bit_range(2, 5), i6::new(0b111));
+        assert_eq!(i6::new(-1).bit_range(0, 6), i6::new(-1));
+        assert_eq!(i6::MIN.bit_range(0, 6), i6::MIN);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bit_range_out_of_bounds() {
+        let _ = u12::MAX.bit_range(4, 13);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bit_range_reversed() {
+        let _ = u12::MAX.bit_range(8, 4);
+    }
+
+    #[test]
+    fn test_with_bit_range() {
+        assert_eq!(
+            u12::new(0b1111_0000_1010).with_bit_range(4, 7, u12::new(0b101)),
+            u12::new(0b1111_0101_1010)
+        );
+        assert_eq!(
+            u12::new(0b1111_1111_1111).with_bit_range(4, 7, u12::new(0b1010)),
+            u12::new(0b1111_1010_1111)
+        );
+        assert_eq!(u12::MAX.with_bit_range(0, 12, u12::new(5)), u12::new(5));
+        assert_eq!(u12::MAX.with_bit_range(3, 3, u12::new(0)), u12::MAX);
+
+        assert_eq!(i6::new(0).with_bit_range(5, 6, i6::new(1)), i6::MIN);
+        assert_eq!(i6::new(-1).with_bit_range(5, 6, i6::new(0)), i6::MAX);
+    }
+
+    #[test]
+    fn test_bit_range_round_trip() {
+        let packed = u24::new(0)
+            .with_bit_range(0, 5, u24::new(17))
+            .with_bit_range(5, 9, u24::new(12))
+            .with_bit_range(9, 24, u24::new(2024));
+
+        assert_eq!(packed.bit_range(0, 5), u24::new(17));
+        assert_eq!(packed.bit_range(5, 9), u24::new(12));
+        assert_eq!(packed.bit_range(9, 24), u24::new(2024));
+
+        let x = i13::new(-1234);
+        assert_eq!(i13::new(0).with_bit_range(0, 13, x.bit_range(0, 13)), x);
+        assert_eq!(x.with_bit_range(3, 9, x.bit_range(3, 9)), x);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_with_bit_range_out_of_bounds() {
+        let _ = u12::MAX.with_bit_range(4, 13, u12::new(0));
+    }
+
+    #[test]
+    fn test_shift_methods() {
+        assert_eq!(u5::new(0b00110).shl_u32(2), u5::new(0b11000));
+        assert_eq!(u5::new(0b00110).shl_u32(4), u5::new(0));
+        assert_eq!(u5::new(0b00110).shr_u32(1), u5::new(0b00011));
+        assert_eq!(u5::MAX.shr_u32(4), u5::new(1));
+
+        assert_eq!(i7::new(1).shl_u32(6), i7::MIN);
+        assert_eq!(i7::new(-2).shl_u32(1), i7::new(-4));
+        assert_eq!(i7::MIN.shr_u32(6), i7::new(-1));
+        assert_eq!(i7::new(-8).shr_u32(2), i7::new(-2));
+
+        assert_eq!(u127::new(1).shl_u32(126).shr_u32(126), u127::new(1));
+    }
+
+    #[test]
+    fn test_wrapping_shifts() {
+        assert_eq!(u5::new(1).wrapping_shl(5), u5::new(1));
+        assert_eq!(u5::new(1).wrapping_shl(7), u5::new(0b00100));
+        assert_eq!(u5::new(0b00110).wrapping_shl(4), u5::new(0));
+        assert_eq!(u5::MAX.wrapping_shr(9), u5::new(1));
+        assert_eq!(u5(0b1110_0100).wrapping_shr(2), u5::new(1));
+        assert_eq!(u1::new(1).wrapping_shl(3), u1::new(1));
+
+        assert_eq!(i6::new(-8).wrapping_shr(2), i6::new(-2));
+        assert_eq!(i6::new(-8).wrapping_shr(8), i6::new(-2));
+        assert_eq!(i6::MIN.wrapping_shr(5), i6::new(-1));
+        assert_eq!(i6::new(1).wrapping_shl(11), i6::MIN);
+        assert_eq!(i6::new(12).wrapping_shr(6), i6::new(12));
+
+        assert_eq!(u127::new(1).wrapping_shl(127), u127::new(1));
+        assert_eq!(i127::MIN.wrapping_shr(126), i127::new(-1));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to shift left with overflow")]
+    fn test_shl_method_overflow() {
+        let _ = u5::new(1).shl_u32(5);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to shift right with overflow")]
+    fn test_shr_method_overflow() {
+        let _ = i7::new(1).shr_u32(7);
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        for base in 0..16u8 {
+            for exp in 0..16u8 {
+                for modulus in 1..16u8 {
+                    let mut expected = 1 % u64::from(modulus);
+                    for _ in 0..exp {
+                        expected = expected * u64::from(base) % u64::from(modulus);
+                    }
+                    assert_eq!(
+                        u4(base).mod_pow(u4(exp), u4(modulus)),
+                        u4(expected as u8),
+                        "{}^{} mod {}",
+                        base,
+                        exp,
+                        modulus
+                    );
+                }
+            }
+        }
+
+        assert_eq!(u5::new(3).mod_pow(u5::new(29), u5::new(31)), u5::new(21));
+        assert_eq!(u63::MAX.mod_pow(u63::new(2), u63::MAX), u63::new(0));
+        assert_eq!(
+            u63::new(2).mod_pow(u63::new(62), u63::MAX),
+            u63::new(1 << 62)
+        );
+        // 2^127 - 1 is prime, so Fermat's little theorem applies.
+        assert_eq!(
+            u127::new(3).mod_pow(u127::MAX - u127::new(1), u127::MAX),
+            u127::new(1)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mod_pow_zero_modulus() {
+        let _ = u7::new(3).mod_pow(u7::new(2), u7::new(0));
+    }
+
+    #[test]
+    fn test_checked_mul() {
+        assert_eq!(u5::new(3).checked_mul(u5::new(10)), Some(u5::new(30)));
+        assert_eq!(u5::new(4).checked_mul(u5::new(8)), None);
+        assert_eq!(u7::new(127).checked_mul(u7::new(127)), None);
+        assert_eq!(i5::new(-4).checked_mul(i5::new(4)), Some(i5::MIN));
+        assert_eq!(i5::new(4).checked_mul(i5::new(4)), None);
+        assert_eq!(i5::MIN.checked_mul(i5::new(-1)), None);
+
+        assert_eq!(u4::new(15).checked_mul(u4::new(2)), None);
+        assert_eq!(u4::new(3).checked_mul(u4::new(5)), Some(u4::new(15)));
+        assert_eq!(
+            u4(0b1111_0011).checked_mul(u4(0b1000_0101)),
+            Some(u4::new(15))
+        );
+        assert_eq!(u7::new(64).checked_mul(u7::new(4)), None);
+        assert_eq!(u7::new(2).checked_mul(u7::new(63)), Some(u7::new(126)));
+    }
+
+    #[test]
+    fn test_mul_widest_unsigned() {
+        let operands = [
+            0,
+            1,
+            2,
+            3,
+            0xffff_ffff,
+            0x1_0000_0000,
+            (1 << 62) - 1,
+            1 << 62,
+            u63::MAX.0 - 1,
+            u63::MAX.0,
+        ];
+        let modulus = 1u128 << 63;
+        for &a in operands.iter() {
+            for &b in operands.iter() {
+                let reference = u128::from(a) * u128::from(b);
+                let expected = u63((reference % modulus) as u64);
+                let overflow = reference > u128::from(u63::MAX.0);
+
+                assert_eq!(u63(a).overflowing_mul(u63(b)), (expected, overflow));
+                assert_eq!(
+                    u63(a).checked_mul(u63(b)),
+                    if overflow { None } else { Some(expected) }
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul_widest_signed() {
+        let operands = [
+            0,
+            1,
+            -1,
+            2,
+            -2,
+            0xffff_ffff,
+            -0x1_0000_0000,
+            (1 << 61) - 1,
+            1 << 61,
+            -(1 << 61),
+            i63::MAX.0,
+            i63::MIN.0,
+            i63::MIN.0 + 1,
+        ];
+        for &a in operands.iter() {
+            for &b in operands.iter() {
+                let reference = i128::from(a) * i128::from(b);
+                // Wrap the reference into the 63 bit range.
+                let expected = i63(((reference << 65) >> 65) as i64);
+                let overflow =
+                    reference > i128::from(i63::MAX.0) || reference < i128::from(i63::MIN.0);
+
+                assert_eq!(i63(a).overflowing_mul(i63(b)), (expected, overflow));
+                assert_eq!(
+                    i63(a).checked_mul(i63(b)),
+                    if overflow { None } else { Some(expected) }
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ux_integer_object_safe() {
+        let values: [&dyn UxInteger; 4] = [&u3::new(5), &i12::new(-7), &u63::MAX, &i127::MIN];
+
+        assert_eq!(values[0].width(), 3);
+        assert_eq!(values[0].as_u128(), 5);
+        assert_eq!(values[0].as_i128(), 5);
+
+        assert_eq!(values[1].width(), 12);
+        assert_eq!(values[1].as_u128(), -7i128 as u128);
+        assert_eq!(values[1].as_i128(), -7);
+
+        assert_eq!(values[2].width(), 63);
+        assert_eq!(values[2].as_u128(), (1 << 63) - 1);
+        assert_eq!(values[2].as_i128(), (1 << 63) - 1);
+
+        assert_eq!(values[3].width(), 127);
+        assert_eq!(values[3].as_i128(), -(1 << 126));
+    }
+
+    #[test]
+    fn test_signedness() {
+        // Decodes the low `width` bits of `bits`, sign extending them for signed types.
+        fn decode<T: Signedness>(bits: u128, width: u32) -> i128 {
+            let field = u128::MAX >> (128 - width);
+            let bits = bits & field;
+            if T::SIGNED && bits >> (width - 1) == 1 {
+                (bits | !field) as i128
+            } else {
+                bits as i128
+            }
+        }
+
+        fn negate<T: Signed + lib::core::ops::Neg<Output = T>>(x: T) -> T {
+            -x
+        }
+
+        assert_eq!(decode::<u5>(0b1_0110, 5), 22);
+        assert_eq!(decode::<i5>(0b1_0110, 5), -10);
+        assert_eq!(decode::<i5>(0b0_0110, 5), 6);
+        assert_eq!(decode::<i127>(u128::MAX, 127), -1);
+        assert_eq!(decode::<u127>(u128::MAX, 127), i128::MAX);
+
+        assert_eq!(
+            [u1::SIGNED, u127::SIGNED, i1::SIGNED, i127::SIGNED],
+            [false, false, true, true]
+        );
+        assert_eq!(negate(i5::new(7)), i5::new(-7));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_ux_integer_boxed() {
+        let values: Vec<Box<dyn UxInteger>> = vec![
+            Box::new(u5::new(17)),
+            Box::new(i7::new(-64)),
+            Box::new(u40::MAX),
+        ];
+
+        let described: Vec<String> = values
+            .iter()
+            .map(|value| format!("{}: {}", value.width(), value.as_i128()))
+            .collect();
+
+        assert_eq!(described, ["5: 17", "7: -64", "40: 1099511627775"]);
+    }
+
+    #[test]
+    fn test_ilog2() {
+        for value in 1..16u8 {
+            let mut expected = 0;
+            while 2u8.pow(expected + 1) <= value {
+                expected += 1;
+            }
+            assert_eq!(u4(value).ilog2(), expected);
+            assert_eq!(u4(value).checked_ilog2(), Some(expected));
+        }
+        assert_eq!(u4(0).checked_ilog2(), None);
+
+        assert_eq!(u4::MAX.ilog2(), u4::BITS - 1);
+        assert_eq!(u127::MAX.ilog2(), u127::BITS - 1);
+        assert_eq!(i7::MAX.ilog2(), i7::BITS - 2);
+        assert_eq!(i7::new(-1).checked_ilog2(), None);
+        assert_eq!(i7(0b0100_0001).checked_ilog2(), None);
+    }
+
+    #[test]
+    fn test_checked_ilog() {
+        for value in 0..64u8 {
+            for base in 0..64u8 {
+                let expected = if value == 0 || base < 2 {
+                    None
+                } else {
+                    let mut log = 0;
+                    let mut power = u32::from(base);
+                    while power <= u32::from(value) {
+                        log += 1;
+                        power *= u32::from(base);
+                    }
+                    Some(log)
+                };
+                assert_eq!(u6(value).checked_ilog(u6(base)), expected);
+            }
+        }
+
+        assert_eq!(i6::new(-8).checked_ilog(i6::new(2)), None);
+        assert_eq!(i6::new(27).checked_ilog(i6::new(3)), Some(3));
+        assert_eq!(i6::new(27).ilog(i6::new(3)), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "argument of integer logarithm must be positive")]
+    fn test_ilog2_zero() {
+        let _ = u4::new(0).ilog2();
+    }
+
+    #[test]
+    #[should_panic(expected = "argument of integer logarithm must be positive")]
+    fn test_ilog_negative() {
+        let _ = i6::new(-8).ilog(i6::new(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "base of integer logarithm must be at least 2")]
+    fn test_ilog_base_too_small() {
+        let _ = u6::new(8).ilog(u6::new(1));
+    }
+
+    #[test]
+    fn test_saturating_add_wide() {
+        assert_eq!(u5::new(10).saturating_add_wide(11), u5::new(21));
+        assert_eq!(u5::new(10).saturating_add_wide(-10), u5::new(0));
+        assert_eq!(u5::new(10).saturating_add_wide(1000), u5::MAX);
+        assert_eq!(u5::new(10).saturating_add_wide(-11), u5::MIN);
+        assert_eq!(u5::new(10).saturating_add_wide(i64::MIN), u5::MIN);
+
+        assert_eq!(i5::new(-10).saturating_add_wide(20), i5::new(10));
+        assert_eq!(i5::new(-10).saturating_add_wide(26), i5::MAX);
+        assert_eq!(i5::new(-10).saturating_add_wide(-7), i5::MIN);
+
+        assert_eq!(u127::MAX.saturating_add_wide(i64::MAX), u127::MAX);
+        assert_eq!(u127::MAX.saturating_add_wide(-1), u127::MAX - u127::new(1));
+        assert_eq!(i127::MIN.saturating_add_wide(i64::MIN), i127::MIN);
+    }
+
+    #[test]
+    fn test_saturating_add_wide_unsigned() {
+        assert_eq!(u5::new(10).saturating_add_wide_unsigned(11), u5::new(21));
+        assert_eq!(u5::new(10).saturating_add_wide_unsigned(22), u5::MAX);
+        assert_eq!(u5::new(10).saturating_add_wide_unsigned(u64::MAX), u5::MAX);
+        assert_eq!(i5::MIN.saturating_add_wide_unsigned(31), i5::MAX);
+        assert_eq!(i5::MIN.saturating_add_wide_unsigned(30), i5::new(14));
+        assert_eq!(u127::MAX.saturating_add_wide_unsigned(u64::MAX), u127::MAX);
+    }
+
+    #[test]
+    fn test_checked_div_rem() {
+        fn div_rem<T: UxInteger + Copy>(a: T, b: T) -> Option<(T, T)> {
+            Some((a.checked_div(b)?, a.checked_rem(b)?))
+        }
+
+        assert_eq!(
+            div_rem(u10::new(100), u10::new(7)),
+            Some((u10::new(14), u10::new(2)))
+        );
+        assert_eq!(div_rem(u10::new(100), u10::new(0)), None);
+        assert_eq!(
+            div_rem(u1::new(1), u1::new(1)),
+            Some((u1::new(1), u1::new(0)))
+        );
+        assert_eq!(div_rem(u1::new(1), u1::new(0)), None);
+        assert_eq!(
+            div_rem(u127::MAX, u127::new(2)),
+            Some((u127::MAX >> 1, u127::new(1)))
+        );
+
+        assert_eq!(
+            div_rem(i6::new(-20), i6::new(6)),
+            Some((i6::new(-3), i6::new(-2)))
+        );
+        assert_eq!(div_rem(i6::new(-20), i6::new(0)), None);
+        assert_eq!(div_rem(i6::MIN, i6::new(-1)), None);
+        assert_eq!(div_rem(i6::MIN, i6::new(1)), Some((i6::MIN, i6::new(0))));
+        assert_eq!(div_rem(i127::MIN, i127::new(-1)), None);
+
+        assert_eq!(i6::MIN.checked_div(i6::new(-1)), None);
+        assert_eq!(i6::MIN.checked_rem(i6::new(-1)), None);
+        assert_eq!(u10::new(5).checked_rem(u10::new(0)), None);
+
+        assert_eq!(u10::new(5).checked_div(u10::new(0)), None);
+        assert_eq!(u10::new(100).checked_div(u10::new(7)), Some(u10::new(14)));
+        assert_eq!(u10::new(100).checked_rem(u10::new(7)), Some(u10::new(2)));
+        assert_eq!(i9::MIN.checked_div(i9::new(-1)), None);
+        assert_eq!(i9::MIN.checked_rem(i9::new(-1)), None);
+        assert_eq!(i9::MIN.checked_div(i9::new(-2)), Some(i9::new(128)));
+        assert_eq!(i9::new(-7).checked_rem(i9::new(2)), Some(i9::new(-1)));
+        assert_eq!(i9::new(-7).checked_div(i9::new(0)), None);
+        assert_eq!(
+            i9(0x7f00 | 0x1ff).checked_div(i9::new(-1)),
+            Some(i9::new(1))
+        );
+    }
+
+    #[test]
+    fn test_to_digits() {
+        let mut buf = [0xff; 12];
+        assert_eq!(u12::new(4095).to_digits(10, &mut buf), [4, 0, 9, 5]);
+        assert_eq!(u12::new(4095).to_digits(16, &mut buf), [15, 15, 15]);
+        assert_eq!(u12::new(0x2a0).to_digits(16, &mut buf), [2, 10, 0]);
+        assert_eq!(u12::new(0).to_digits(10, &mut buf), [0]);
+        assert_eq!(u12::MAX.to_digits(2, &mut buf), [1; 12]);
+
+        assert_eq!(i12::new(-2048).to_digits(10, &mut buf), [2, 0, 4, 8]);
+        assert_eq!(i12::new(-7).to_digits(3, &mut buf), [2, 1]);
+
+        let mut buf = [0; 127];
+        assert_eq!(i127::MIN.to_digits(2, &mut buf).len(), 127);
+        assert_eq!(
+            u127::MAX.to_digits(256, &mut buf),
+            [
+                0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+                0xff, 0xff
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_to_digits_small_buffer() {
+        let _ = u12::new(100).to_digits(10, &mut [0; 2]);
+    }
+
+    #[test]
+    fn test_wrapping_add_slice() {
+        let src: [u12; 64] = core::array::from_fn(|i| u12::new(i as u16 * 61));
+        let mut dst: [u12; 64] = core::array::from_fn(|i| u12::new(4095 - i as u16 * 13));
+        let expected: [u12; 64] = core::array::from_fn(|i| dst[i].wrapping_add(src[i]));
+        u12::wrapping_add_slice(&mut dst, &src);
+        assert_eq!(dst, expected);
+
+        let src: [i7; 32] = core::array::from_fn(|i| i7::new(i as i8 * 4 - 64));
+        let mut dst: [i7; 32] = core::array::from_fn(|i| i7::new(63 - i as i8 * 3));
+        let expected: [i7; 32] = core::array::from_fn(|i| dst[i].wrapping_add(src[i]));
+        i7::wrapping_add_slice(&mut dst, &src);
+        assert_eq!(dst, expected);
+
+        let mut dst = [u5(0b1110_0001), u5::MAX];
+        u5::wrapping_add_slice(&mut dst, &[u5(0b0100_0001), u5::new(2)]);
+        assert_eq!(dst, [u5::new(2), u5::new(1)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_wrapping_add_slice_length_mismatch() {
+        u5::wrapping_add_slice(&mut [u5::new(1); 3], &[u5::new(1); 2]);
+    }
+
+    #[test]
+    fn test_ord_consistent_with_eq() {
+        // Every bit pattern of the backing type, most of them with bits set above the width.
+        for a in 0..=255u8 {
+            for b in 0..=255u8 {
+                assert_eq!(u5(a).cmp(&u5(b)) == Ordering::Equal, u5(a) == u5(b));
+                assert_eq!(u5(a).cmp(&u5(b)), (a & 0x1f).cmp(&(b & 0x1f)));
+
+                let (a, b) = (a as i8, b as i8);
+                assert_eq!(i5(a).cmp(&i5(b)) == Ordering::Equal, i5(a) == i5(b));
+                assert_eq!(i5(a).cmp(&i5(b)), ((a << 3) >> 3).cmp(&((b << 3) >> 3)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_unstable_unmasked() {
+        let mut unmasked = [u5(0b1110_0011), u5(1), u5(0b0010_0000), u5(0b1000_0010)];
+        unmasked.sort_unstable();
+        assert_eq!(unmasked, [u5::new(0), u5::new(1), u5::new(2), u5::new(3)]);
+
+        let mut unmasked = [i5(0b0111_0000), i5(-1), i5(0b0100_1111), i5(2)];
+        unmasked.sort_unstable();
+        assert_eq!(
+            unmasked,
+            [i5::new(-16), i5::new(-1), i5::new(2), i5::new(15)]
+        );
+    }
 
-define_unsigned!(#[doc="The 89-bit unsigned integer type."], u89, 89, u128);
-define_unsigned!(#[doc="The 90-bit unsigned integer type."], u90, 90, u128);
-define_unsigned!(#[doc="The 91-bit unsigned integer type."], u91, 91, u128);
-define_unsigned!(#[doc="The 92-bit unsigned integer type."], u92, 92, u128);
-define_unsigned!(#[doc="The 93-bit unsigned integer type."], u93, 93, u128);
-define_unsigned!(#[doc="The 94-bit unsigned integer type."], u94, 94, u128);
-define_unsigned!(#[doc="The 95-bit unsigned integer type."], u95, 95, u128);
-define_unsigned!(#[doc="The 96-bit unsigned integer type."], u96, 96, u128);
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_btree_map_unmasked_keys() {
+        use std::collections::BTreeMap;
+
+        let mut map = BTreeMap::new();
+        map.insert(u5(0b1110_0011), "three");
+        map.insert(u5(0b0000_0001), "one");
+        map.insert(u5(0b0101_1111), "thirty one");
+        map.insert(u5(0b1000_0010), "two");
+        map.insert(u5(0b0010_0011), "three again");
+
+        assert_eq!(map.len(), 4);
+        assert_eq!(
+            map.keys().copied().collect::<Vec<_>>(),
+            [u5::new(1), u5::new(2), u5::new(3), u5::new(31)]
+        );
+        assert_eq!(map.get(&u5::new(3)), Some(&"three again"));
+        assert_eq!(map.get(&u5(0b1111_1111)), Some(&"thirty one"));
+        assert_eq!(map.range(u5(0b1100_0010)..u5::new(4)).count(), 2);
+
+        let mut map = BTreeMap::new();
+        map.insert(i5(0b0001_0000), "min");
+        map.insert(i5(0b0110_1111), "max");
+        map.insert(i5(0b0101_1111), "minus one");
+
+        assert_eq!(
+            map.values().copied().collect::<Vec<_>>(),
+            ["min", "minus one", "max"]
+        );
+        assert_eq!(map.get(&i5::MIN), Some(&"min"));
+        assert_eq!(map.get(&i5::new(-1)), Some(&"minus one"));
+    }
 
-define_unsigned!(#[doc="The 97-bit unsigned integer type."], u97, 97, u128);
-define_unsigned!(#[doc="The 98-bit unsigned integer type."], u98, 98, u128);
-define_unsigned!(#[doc="The 99-bit unsigned integer type."], u99, 99, u128);
-define_unsigned!(#[doc="The 100-bit unsigned integer type."], u100, 100, u128);
-define_unsigned!(#[doc="The 101-bit unsigned integer type."], u101, 101, u128);
-define_unsigned!(#[doc="The 102-bit unsigned integer type."], u102, 102, u128);
-define_unsigned!(#[doc="The 103-bit unsigned integer type."], u103, 103, u128);
-define_unsigned!(#[doc="The 104-bit unsigned integer type."], u104, 104, u128);
+    #[test]
+    fn test_new_from() {
+        assert_eq!(u5::new_from(17u32), Ok(u5::new(17)));
+        assert_eq!(u5::new_from(31u64), Ok(u5::MAX));
+        assert_eq!(u5::new_from(0i8), Ok(u5::MIN));
+        assert_eq!(u5::new_from(32u32).unwrap_err().value(), Some(32));
+        assert_eq!(u5::new_from(-1i32).unwrap_err().value(), Some(-1));
+        assert_eq!(
+            u5::new_from(u128::MAX).unwrap_err().value(),
+            Some(i128::MAX)
+        );
+
+        assert_eq!(i5::new_from(-16i32), Ok(i5::MIN));
+        assert_eq!(i5::new_from(15usize), Ok(i5::MAX));
+        assert_eq!(i5::new_from(-17i32).unwrap_err().min(), Some(-16));
+        assert!(i5::new_from(16i32).is_err());
+
+        assert_eq!(u127::new_from(u128::MAX >> 1), Ok(u127::MAX));
+        assert!(u127::new_from(u128::MAX).is_err());
+        assert_eq!(i127::new_from(i128::MIN >> 1), Ok(i127::MIN));
+    }
 
-define_unsigned!(#[doc="The 105-bit unsigned integer type."], u105, 105, u128);
-define_unsigned!(#[doc="The 106-bit unsigned integer type."], u106, 106, u128);
-define_unsigned!(#[doc="The 107-bit unsigned integer type."], u107, 107, u128);
-define_unsigned!(#[doc="The 108-bit unsigned integer type."], u108, 108, u128);
-define_unsigned!(#[doc="The 109-bit unsigned integer type."], u109, 109, u128);
-define_unsigned!(#[doc="The 110-bit unsigned integer type."], u110, 110, u128);
-define_unsigned!(#[doc="The 111-bit unsigned integer type."], u111, 111, u128);
-define_unsigned!(#[doc="The 112-bit unsigned integer type."], u112, 112, u128);
+    #[test]
+    fn test_signed_diff() {
+        assert_eq!(u5::new(2).signed_diff(u5::new(5)), i6::new(-3));
+        assert_eq!(u5::new(5).signed_diff(u5::new(2)), i6::new(3));
+        assert_eq!(u5::MIN.signed_diff(u5::MAX), i6::new(-31));
+        assert_eq!(u5::MAX.signed_diff(u5::MIN), i6::new(31));
+
+        for a in 0..=31 {
+            for b in 0..=31 {
+                let expected = i6::new(a - b);
+                assert_eq!(u5::new(a as u8).signed_diff(u5::new(b as u8)), expected);
+            }
+        }
 
-define_unsigned!(#[doc="The 113-bit unsigned integer type."], u113, 113, u128);
-define_unsigned!(#[doc="The 114-bit unsigned integer type."], u114, 114, u128);
-define_unsigned!(#[doc="The 115-bit unsigned integer type."], u115, 115, u128);
-define_unsigned!(#[doc="The 116-bit unsigned integer type."], u116, 116, u128);
-define_unsigned!(#[doc="The 117-bit unsigned integer type."], u117, 117, u128);
-define_unsigned!(#[doc="The 118-bit unsigned integer type."], u118, 118, u128);
-define_unsigned!(#[doc="The 119-bit unsigned integer type."], u119, 119, u128);
-define_unsigned!(#[doc="The 120-bit unsigned integer type."], u120, 120, u128);
+        assert_eq!(u7::MIN.signed_diff(u7::MAX), -127i8);
+        assert_eq!(u7::MAX.signed_diff(u7::MIN), 127i8);
+        assert_eq!(u63::MIN.signed_diff(u63::MAX), -i64::MAX);
+        assert_eq!(u63::MAX.signed_diff(u63::MIN), i64::MAX);
+        assert_eq!(u127::MAX.signed_diff(u127::MIN), i128::MAX);
+        assert_eq!(u127::MIN.signed_diff(u127::MAX), -i128::MAX);
+    }
 
-define_unsigned!(#[doc="The 121-bit unsigned integer type."], u121, 121, u128);
-define_unsigned!(#[doc="The 122-bit unsigned integer type."], u122, 122, u128);
-define_unsigned!(#[doc="The 123-bit unsigned integer type."], u123, 123, u128);
-define_unsigned!(#[doc="The 124-bit unsigned integer type."], u124, 124, u128);
-define_unsigned!(#[doc="The 125-bit unsigned integer type."], u125, 125, u128);
-define_unsigned!(#[doc="The 126-bit unsigned integer type."], u126, 126, u128);
-define_unsigned!(#[doc="The 127-bit unsigned integer type."], u127, 127, u128);
+    macro_rules! test_rotate_reverse {
+        ($($name:ident: $type:ident),*) => {$(
+            let samples = [
+                $name::MIN,
+                $name::MAX,
+                $name(0),
+                $name(1),
+                $name(0x5555_5555_5555_5555_5555_5555_5555_5555u128 as $type).mask(),
+                $name(0x0123_4567_89ab_cdef_fedc_ba98_7654_3210u128 as $type).mask(),
+                $name(0xdead_beef_0bad_f00d_dead_beef_0bad_f00du128 as $type).mask(),
+            ];
+            for &x in samples.iter() {
+                assert_eq!(x.reverse_bits().reverse_bits(), x);
+                assert_eq!(x.rotate_left($name::BITS), x);
+                assert_eq!(x.rotate_right($name::BITS), x);
+                for n in [1, 3, $name::BITS - 1, $name::BITS + 2] {
+                    assert_eq!(x.rotate_left(n).rotate_right(n), x);
+                }
+            }
+        )*};
+    }
 
-define_signed!(#[doc="The 1-bit signed integer type."], i1, 1, i8);
-define_signed!(#[doc="The 2-bit signed integer type."], i2, 2, i8);
-define_signed!(#[doc="The 3-bit signed integer type."], i3, 3, i8);
-define_signed!(#[doc="The 4-bit signed integer type."], i4, 4, i8);
-define_signed!(#[doc="The 5-bit signed integer type."], i5, 5, i8);
-define_signed!(#[doc="The 6-bit signed integer type."], i6, 6, i8);
-define_signed!(#[doc="The 7-bit signed integer type."], i7, 7, i8);
+    #[test]
+    fn test_rotate_reverse() {
+        assert_eq!(u5::new(0b10000).rotate_left(1), u5::new(0b00001));
+        assert_eq!(u5::new(0b10110).rotate_left(2), u5::new(0b11010));
+        assert_eq!(u5::new(0b10110).rotate_right(2), u5::new(0b10101));
+        assert_eq!(i6::new(-32).rotate_left(1), i6::new(1));
+        assert_eq!(i6::new(1).rotate_right(1), i6::MIN);
+
+        assert_eq!(u4::new(0b0001).reverse_bits(), u4::new(0b1000));
+        assert_eq!(u3::new(0b110).reverse_bits(), u3::new(0b011));
+        assert_eq!(u12::new(0xf01).reverse_bits(), u12::new(0x80f));
+        assert_eq!(i6::new(1).reverse_bits(), i6::MIN);
+        assert_eq!(i6::new(-2).reverse_bits(), i6::new(0b011111));
+    }
 
-define_signed!(#[doc="The 9-bit signed integer type."], i9, 9, i16);
-define_signed!(#[doc="The 10-bit signed integer type."], i10, 10, i16);
-define_signed!(#[doc="The 11-bit signed integer type."], i11, 11, i16);
-define_signed!(#[doc="The 12-bit signed integer type."], i12, 12, i16);
-define_signed!(#[doc="The 13-bit signed integer type."], i13, 13, i16);
-define_signed!(#[doc="The 14-bit signed integer type."], i14, 14, i16);
-define_signed!(#[doc="The 15-bit signed integer type."], i15, 15, i16);
+    #[test]
+    fn test_reverse_bits_signed() {
+        assert_eq!(i6::new(3).reverse_bits(), i6::new(-16));
+        assert_eq!(i6::new(-16).reverse_bits(), i6::new(3));
+        assert_eq!(i6::MIN.reverse_bits(), i6::new(1));
+        assert_eq!(i6::new(-1).reverse_bits(), i6::new(-1));
+        assert_eq!(i6::new(0).reverse_bits(), i6::new(0));
+        assert_eq!(i6(0b0100_0011).reverse_bits(), i6::new(-16));
+        assert_eq!(i6(0b0111_0000).reverse_bits(), i6::new(0b00_0011));
+        assert_eq!(i127::new(1).reverse_bits(), i127::MIN);
+        assert_eq!(i1::new(-1).reverse_bits(), i1::new(-1));
+
+        assert_eq!(u3::new(0b001).reverse_bits(), u3::new(0b100));
+        assert_eq!(u3(0b1111_1001).reverse_bits(), u3::new(0b100));
+        assert_eq!(u12::new(0x001).reverse_bits(), u12::new(0x800));
+        assert_eq!(u12(0xf001).reverse_bits(), u12::new(0x800));
+    }
 
-define_signed!(#[doc="The 17-bit signed integer type."], i17, 17, i32);
-define_signed!(#[doc="The 18-bit signed integer type."], i18, 18, i32);
-define_signed!(#[doc="The 19-bit signed integer type."], i19, 19, i32);
-define_signed!(#[doc="The 20-bit signed integer type."], i20, 20, i32);
-define_signed!(#[doc="The 21-bit signed integer type."], i21, 21, i32);
-define_signed!(#[doc="The 22-bit signed integer type."], i22, 22, i32);
-define_signed!(#[doc="The 23-bit signed integer type."], i23, 23, i32);
-define_signed!(#[doc="The 24-bit signed integer type."], i24, 24, i32);
+    #[test]
+    fn test_rotate_beyond_width() {
+        assert_eq!(u5::new(0b10000).rotate_left(6), u5::new(0b00001));
+        assert_eq!(u5::new(0b10110).rotate_left(12), u5::new(0b11010));
+        assert_eq!(u5::new(0b10110).rotate_right(7), u5::new(0b10101));
+        assert_eq!(u5::new(0b10110).rotate_left(0), u5::new(0b10110));
+        assert_eq!(u5::new(0b10110).rotate_left(5), u5::new(0b10110));
+        assert_eq!(u5::new(0b10110).rotate_right(10), u5::new(0b10110));
+        assert_eq!(u12::new(0x801).rotate_left(12), u12::new(0x801));
+        assert_eq!(u5(0b1111_0001).rotate_right(1), u5::new(0b11000));
+
+        assert_eq!(u12::new(0x801).rotate_left(4), u12::new(0x018));
+        assert_eq!(u12::new(0x801).rotate_left(16), u12::new(0x018));
+        assert_eq!(u12::new(0x801).rotate_right(28), u12::new(0x180));
+
+        assert_eq!(i6::new(-32).rotate_left(7), i6::new(1));
+        assert_eq!(i6::new(1).rotate_right(13), i6::MIN);
+
+        assert_eq!(u1::new(1).rotate_left(u32::MAX), u1::new(1));
+        assert_eq!(u127::new(1).rotate_right(128), u127::new(1 << 126));
+        assert_eq!(u63::new(1 << 62).rotate_left(u32::MAX), u63::new(1 << 2));
+    }
 
-define_signed!(#[doc="The 25-bit signed integer type."], i25, 25, i32);
-define_signed!(#[doc="The 26-bit signed integer type."], i26, 26, i32);
-define_signed!(#[doc="The 27-bit signed integer type."], i27, 27, i32);
-define_signed!(#[doc="The 28-bit signed integer type."], i28, 28, i32);
-define_signed!(#[doc="The 29-bit signed integer type."], i29, 29, i32);
-define_signed!(#[doc="The 30-bit signed integer type."], i30, 30, i32);
-define_signed!(#[doc="The 31-bit signed integer type."], i31, 31, i32);
+    #[test]
+    fn test_rotate_reverse_all_widths() {
+        test_rotate_reverse!(
+        u1: u8, u2: u8, u3: u8, u4: u8, u5: u8, u6: u8, u7: u8, u9: u16, u10: u16, u11: u16,
+        u12: u16, u13: u16, u14: u16, u15: u16, u17: u32, u18: u32, u19: u32, u20: u32, u21: u32,
+        u22: u32, u23: u32, u24: u32, u25: u32, u26: u32, u27: u32, u28: u32, u29: u32, u30: u32,
+        u31: u32, u33: u64, u34: u64, u35: u64, u36: u64, u37: u64, u38: u64, u39: u64, u40: u64,
+        u41: u64, u42: u64, u43: u64, u44: u64, u45: u64, u46: u64, u47: u64, u48: u64, u49: u64,
+        u50: u64, u51: u64, u52: u64, u53: u64, u54: u64, u55: u64, u56: u64, u57: u64, u58: u64,
+        u59: u64, u60: u64, u61: u64, u62: u64, u63: u64, u65: u128, u66: u128, u67: u128,
+        u68: u128, u69: u128, u70: u128, u71: u128, u72: u128, u73: u128, u74: u128, u75: u128,
+        u76: u128, u77: u128, u78: u128, u79: u128, u80: u128, u81: u128, u82: u128, u83: u128,
+        u84: u128, u85: u128, u86: u128, u87: u128, u88: u128, u89: u128, u90: u128, u91: u128,
+        u92: u128, u93: u128, u94: u128, u95: u128, u96: u128, u97: u128, u98: u128, u99: u128,
+        u100: u128, u101: u128, u102: u128, u103: u128, u104: u128, u105: u128, u106: u128,
+        u107: u128, u108: u128, u109: u128, u110: u128, u111: u128, u112: u128, u113: u128,
+        u114: u128, u115: u128, u116: u128, u117: u128, u118: u128, u119: u128, u120: u128,
+        u121: u128, u122: u128, u123: u128, u124: u128, u125: u128, u126: u128, u127: u128
+        );
+        test_rotate_reverse!(
+        i1: i8, i2: i8, i3: i8, i4: i8, i5: i8, i6: i8, i7: i8, i9: i16, i10: i16, i11: i16,
+        i12: i16, i13: i16, i14: i16, i15: i16, i17: i32, i18: i32, i19: i32, i20: i32, i21: i32,
+        i22: i32, i23: i32, i24: i32, i25: i32, i26: i32, i27: i32, i28: i32, i29: i32, i30: i32,
+        i31: i32, i33: i64, i34: i64, i35: i64, i36: i64, i37: i64, i38: i64, i39: i64, i40: i64,
+        i41: i64, i42: i64, i43: i64, i44: i64, i45: i64, i46: i64, i47: i64, i48: i64, i49: i64,
+        i50: i64, i51: i64, i52: i64, i53: i64, i54: i64, i55: i64, i56: i64, i57: i64, i58: i64,
+        i59: i64, i60: i64, i61: i64, i62: i64, i63: i64, i65: i128, i66: i128, i67: i128,
+        i68: i128, i69: i128, i70: i128, i71: i128, i72: i128, i73: i128, i74: i128, i75: i128,
+        i76: i128, i77: i128, i78: i128, i79: i128, i80: i128, i81: i128, i82: i128, i83: i128,
+        i84: i128, i85: i128, i86: i128, i87: i128, i88: i128, i89: i128, i90: i128, i91: i128,
+        i92: i128, i93: i128, i94: i128, i95: i128, i96: i128, i97: i128, i98: i128, i99: i128,
+        i100: i128, i101: i128, i102: i128, i103: i128, i104: i128, i105: i128, i106: i128,
+        i107: i128, i108: i128, i109: i128, i110: i128, i111: i128, i112: i128, i113: i128,
+        i114: i128, i115: i128, i116: i128, i117: i128, i118: i128, i119: i128, i120: i128,
+        i121: i128, i122: i128, i123: i128, i124: i128, i125: i128, i126: i128, i127: i128
+        );
+    }
 
-define_signed!(#[doc="The 33-bit signed integer type."], i33, 33, i64);
-define_signed!(#[doc="The 34-bit signed integer type."], i34, 34, i64);
-define_signed!(#[doc="The 35-bit signed integer type."], i35, 35, i64);
-define_signed!(#[doc="The 36-bit signed integer type."], i36, 36, i64);
-define_signed!(#[doc="The 37-bit signed integer type."], i37, 37, i64);
-define_signed!(#[doc="The 38-bit signed integer type."], i38, 38, i64);
-define_signed!(#[doc="The 39-bit signed integer type."], i39, 39, i64);
-define_signed!(#[doc="The 40-bit signed integer type."], i40, 40, i64);
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_formatting_ignores_storage_above_width() {
+        // Shifting leaves bits above the width in the backing storage, which the
+        // formatters must not show.
+        let shifted = u5::MAX << 3;
+        assert_eq!(shifted.0, 0b1111_1000);
+        assert_eq!(format!("{}", shifted), "24");
+        assert_eq!(
+            format!("{:x} {:X} {:o} {:b}", shifted, shifted, shifted, shifted),
+            "18 18 30 11000"
+        );
+        assert_eq!(format!("{}", i5::new(7) << 2), "-4");
+        assert_eq!(format!("{}", u63::MAX << 1), format!("{}", u63::MAX.0 - 1));
+        assert_eq!(format!("{}", i127::MAX << 1), "-2");
+
+        for value in 0..1 << 12 {
+            let dirty = u12(value | 0xf000);
+            assert_eq!(format!("{}", dirty), format!("{}", value));
+            assert_eq!(format!("{:x}", dirty), format!("{:x}", value));
+            assert_eq!(format!("{:#X}", dirty), format!("{:#X}", value));
+            assert_eq!(format!("{:o}", dirty), format!("{:o}", value));
+            assert_eq!(format!("{:b}", dirty), format!("{:b}", value));
+        }
+        for value in i20::MIN.0..=i20::MAX.0 {
+            assert_eq!(
+                format!("{}", i20(value ^ (0x5a << 24))),
+                format!("{}", value)
+            );
+        }
+    }
 
-define_signed!(#[doc="The 41-bit signed integer type."], i41, 41, i64);
-define_signed!(#[doc="The 42-bit signed integer type."], i42, 42, i64);
-define_signed!(#[doc="The 43-bit signed integer type."], i43, 43, i64);
-define_signed!(#[doc="The 44-bit signed integer type."], i44, 44, i64);
-define_signed!(#[doc="The 45-bit signed integer type."], i45, 45, i64);
-define_signed!(#[doc="The 46-bit signed integer type."], i46, 46, i64);
-define_signed!(#[doc="The 47-bit signed integer type."], i47, 47, i64);
-define_signed!(#[doc="The 48-bit signed integer type."], i48, 48, i64);
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_display_padding() {
+        assert_eq!(format!("{:>6}", i5::new(-3)), "    -3");
+        assert_eq!(format!("{:<6}|", i5::new(-3)), "-3    |");
+        assert_eq!(format!("{:^6}", i5::new(-3)), "  -3  ");
+        assert_eq!(format!("{:06}", i5::new(-3)), "-00003");
+        assert_eq!(format!("{:+05}", i5::new(3)), "+0003");
+        assert_eq!(format!("{:05}", i5::MIN), "-0016");
+        assert_eq!(format!("{:*>4}", u5::new(7)), "***7");
+
+        assert_eq!(format!("{:06}", i5(0b1111_1101u8 as i8)), "-00003");
+        assert_eq!(format!("{:06}", i5(0b0111_1101)), "-00003");
+        assert_eq!(format!("{:>6}", u5(0b1110_0111)), "     7");
+
+        for value in i12::MIN.0..=i12::MAX.0 {
+            assert_eq!(format!("{:08}", i12(value)), format!("{:08}", value));
+            assert_eq!(format!("{:>8}", i12(value)), format!("{:>8}", value));
+        }
+    }
 
-define_signed!(#[doc="The 49-bit signed integer type."], i49, 49, i64);
-define_signed!(#[doc="The 50-bit signed integer type."], i50, 50, i64);
-define_signed!(#[doc="The 51-bit signed integer type."], i51, 51, i64);
-define_signed!(#[doc="The 52-bit signed integer type."], i52, 52, i64);
-define_signed!(#[doc="The 53-bit signed integer type."], i53, 53, i64);
-define_signed!(#[doc="The 54-bit signed integer type."], i54, 54, i64);
-define_signed!(#[doc="The 55-bit signed integer type."], i55, 55, i64);
-define_signed!(#[doc="The 56-bit signed integer type."], i56, 56, i64);
+    #[test]
+    fn test_multiple_of() {
+        assert_eq!(u6::new(16).next_multiple_of(u6::new(8)), u6::new(16));
+        assert_eq!(u6::new(17).next_multiple_of(u6::new(8)), u6::new(24));
+        assert_eq!(u6::new(17).prev_multiple_of(u6::new(8)), u6::new(16));
+        assert_eq!(u6::new(16).prev_multiple_of(u6::new(8)), u6::new(16));
+        assert_eq!(u6::new(7).prev_multiple_of(u6::new(8)), u6::new(0));
+        assert_eq!(u6::new(0).next_multiple_of(u6::new(8)), u6::new(0));
+
+        assert_eq!(u6::new(57).checked_next_multiple_of(u6::new(8)), None);
+        assert_eq!(
+            u6::new(56).checked_next_multiple_of(u6::new(8)),
+            Some(u6::new(56))
+        );
+        assert_eq!(u6::MAX.checked_next_multiple_of(u6::new(21)), Some(u6::MAX));
+        assert_eq!(u127::MAX.checked_next_multiple_of(u127::new(2)), None);
+
+        assert_eq!(u6::new(17).checked_next_multiple_of(u6::new(0)), None);
+        assert_eq!(u6::new(17).checked_prev_multiple_of(u6::new(0)), None);
+    }
 
-define_signed!(#[doc="The 57-bit signed integer type."], i57, 57, i64);
-define_signed!(#[doc="The 58-bit signed integer type."], i58, 58, i64);
-define_signed!(#[doc="The 59-bit signed integer type."], i59, 59, i64);
-define_signed!(#[doc="The 60-bit signed integer type."], i60, 60, i64);
-define_signed!(#[doc="The 61-bit signed integer type."], i61, 61, i64);
-define_signed!(#[doc="The 62-bit signed integer type."], i62, 62, i64);
-define_signed!(#[doc="The 63-bit signed integer type."], i63, 63, i64);
+    #[test]
+    #[should_panic]
+    fn test_next_multiple_of_zero() {
+        let _ = u6::new(17).next_multiple_of(u6::new(0));
+    }
 
-define_signed!(#[doc="The 65-bit signed integer type."], i65, 65, i128);
-define_signed!(#[doc="The 66-bit signed integer type."], i66, 66, i128);
-define_signed!(#[doc="The 67-bit signed integer type."], i67, 67, i128);
-define_signed!(#[doc="The 68-bit signed integer type."], i68, 68, i128);
-define_signed!(#[doc="The 69-bit signed integer type."], i69, 69, i128);
-define_signed!(#[doc="The 70-bit signed integer type."], i70, 70, i128);
-define_signed!(#[doc="The 71-bit signed integer type."], i71, 71, i128);
-define_signed!(#[doc="The 72-bit signed integer type."], i72, 72, i128);
+    #[test]
+    #[should_panic]
+    fn test_prev_multiple_of_zero() {
+        let _ = u6::new(17).prev_multiple_of(u6::new(0));
+    }
 
-define_signed!(#[doc="The 73-bit signed integer type."], i73, 73, i128);
-define_signed!(#[doc="The 74-bit signed integer type."], i74, 74, i128);
-define_signed!(#[doc="The 75-bit signed integer type."], i75, 75, i128);
-define_signed!(#[doc="The 76-bit signed integer type."], i76, 76, i128);
-define_signed!(#[doc="The 77-bit signed integer type."], i77, 77, i128);
-define_signed!(#[doc="The 78-bit signed integer type."], i78, 78, i128);
-define_signed!(#[doc="The 79-bit signed integer type."], i79, 79, i128);
-define_signed!(#[doc="The 80-bit signed integer type."], i80, 80, i128);
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_next_multiple_of_overflow() {
+        let _ = u6::new(57).next_multiple_of(u6::new(8));
+    }
 
-define_signed!(#[doc="The 81-bit signed integer type."], i81, 81, i128);
-define_signed!(#[doc="The 82-bit signed integer type."], i82, 82, i128);
-define_signed!(#[doc="The 83-bit signed integer type."], i83, 83, i128);
-define_signed!(#[doc="The 84-bit signed integer type."], i84, 84, i128);
-define_signed!(#[doc="The 85-bit signed integer type."], i85, 85, i128);
-define_signed!(#[doc="The 86-bit signed integer type."], i86, 86, i128);
-define_signed!(#[doc="The 87-bit signed integer type."], i87, 87, i128);
-define_signed!(#[doc="The 88-bit signed integer type."], i88, 88, i128);
+    #[test]
+    fn test_to_packed_bits() {
+        assert_eq!(u1::new(1).to_packed_bits(), (1, 1));
+        assert_eq!(u5::new(0b10110).to_packed_bits(), (0b10110, 5));
+        assert_eq!(u12::MAX.to_packed_bits(), (0xfff, 12));
+        assert_eq!(u63::MAX.to_packed_bits(), (u64::MAX >> 1, 63));
+
+        assert_eq!(i5::new(-1).to_packed_bits(), (0b11111, 5));
+        assert_eq!(i5::MIN.to_packed_bits(), (0b10000, 5));
+        assert_eq!(i5::new(3).to_packed_bits(), (0b00011, 5));
+        assert_eq!(i63::MIN.to_packed_bits(), (1 << 62, 63));
+        assert_eq!(i5(0b0110_0011).to_packed_bits(), (0b00011, 5));
+    }
 
-define_signed!(#[doc="The 89-bit signed integer type."], i89, 89, i128);
-define_signed!(#[doc="The 90-bit signed integer type."], i90, 90, i128);
-define_signed!(#[doc="The 91-bit signed integer type."], i91, 91, i128);
-define_signed!(#[doc="The 92-bit signed integer type."], i92, 92, i128);
-define_signed!(#[doc="The 93-bit signed integer type."], i93, 93, i128);
-define_signed!(#[doc="The 94-bit signed integer type."], i94, 94, i128);
-define_signed!(#[doc="The 95-bit signed integer type."], i95, 95, i128);
-define_signed!(#[doc="The 96-bit signed integer type."], i96, 96, i128);
+    #[test]
+    fn test_checked_add() {
+        assert_eq!(u5::new(3).checked_add(u5::new(4)), Some(u5::new(7)));
+        assert_eq!(u5::MAX.checked_add(u5::new(1)), None);
+        assert_eq!(u7::MAX.checked_add(u7::MAX), None);
+        assert_eq!(i5::MIN.checked_add(i5::new(-1)), None);
+        assert_eq!(i5::MAX.checked_add(i5::MIN), Some(i5::new(-1)));
+        assert_eq!(u127::MAX.checked_add(u127::new(1)), None);
+        assert_eq!(i127::MIN.checked_add(i127::new(-1)), None);
+    }
 
-define_signed!(#[doc="The 97-bit signed integer type."], i97, 97, i128);
-define_signed!(#[doc="The 98-bit signed integer type."], i98, 98, i128);
-define_signed!(#[doc="The 99-bit signed integer type."], i99, 99, i128);
-define_signed!(#[doc="The 100-bit signed integer type."], i100, 100, i128);
-define_signed!(#[doc="The 101-bit signed integer type."], i101, 101, i128);
-define_signed!(#[doc="The 102-bit signed integer type."], i102, 102, i128);
-define_signed!(#[doc="The 103-bit signed integer type."], i103, 103, i128);
-define_signed!(#[doc="The 104-bit signed integer type."], i104, 104, i128);
+    #[test]
+    fn test_try_sum_indexed() {
+        let values = [u6::new(10), u6::new(20), u6::new(40), u6::new(1)];
+        assert_eq!(
+            u6::try_sum_indexed(values[..2].iter().copied()),
+            Ok(u6::new(30))
+        );
+        assert_eq!(u6::try_sum_indexed(values), Err(2));
+        assert_eq!(u6::try_sum_indexed([]), Ok(u6::new(0)));
+        assert_eq!(u6::try_sum_indexed([u6::MAX, u6::new(0)]), Ok(u6::MAX));
+
+        assert_eq!(
+            i4::try_sum_indexed([i4::new(7), i4::new(-8), i4::new(-7)]),
+            Ok(i4::new(-8))
+        );
+        assert_eq!(
+            i4::try_sum_indexed([i4::new(-8), i4::new(-1), i4::new(7)]),
+            Err(1)
+        );
+    }
 
-define_signed!(#[doc="The 105-bit signed integer type."], i105, 105, i128);
-define_signed!(#[doc="The 106-bit signed integer type."], i106, 106, i128);
-define_signed!(#[doc="The 107-bit signed integer type."], i107, 107, i128);
-define_signed!(#[doc="The 108-bit signed integer type."], i108, 108, i128);
-define_signed!(#[doc="The 109-bit signed integer type."], i109, 109, i128);
-define_signed!(#[doc="The 110-bit signed integer type."], i110, 110, i128);
-define_signed!(#[doc="The 111-bit signed integer type."], i111, 111, i128);
-define_signed!(#[doc="The 112-bit signed integer type."], i112, 112, i128);
+    #[test]
+    fn test_min_max_primitive() {
+        assert_eq!(u5::max_primitive(), 31u8);
+        assert_eq!(u5::min_primitive(), 0u8);
+        assert_eq!(u33::max_primitive(), 0x1_ffff_ffffu64);
 
-define_signed!(#[doc="The 113-bit signed integer type."], i113, 113, i128);
-define_signed!(#[doc="The 114-bit signed integer type."], i114, 114, i128);
-define_signed!(#[doc="The 115-bit signed integer type."], i115, 115, i128);
-define_signed!(#[doc="The 116-bit signed integer type."], i116, 116, i128);
-define_signed!(#[doc="The 117-bit signed integer type."], i117, 117, i128);
-define_signed!(#[doc="The 118-bit signed integer type."], i118, 118, i128);
-define_signed!(#[doc="The 119-bit signed integer type."], i119, 119, i128);
-define_signed!(#[doc="The 120-bit signed integer type."], i120, 120, i128);
+        assert_eq!(i5::max_primitive(), 15i8);
+        assert_eq!(i5::min_primitive(), -16i8);
+        assert_eq!(i127::min_primitive(), i128::MIN >> 1);
+
+        assert!((i5::min_primitive()..=i5::max_primitive()).contains(&-16));
+        assert!(!(i5::min_primitive()..=i5::max_primitive()).contains(&16));
+    }
+
+    #[test]
+    fn test_clamp_assign() {
+        let (min, max) = (u6::new(10), u6::new(40));
+
+        let mut x = u6::new(3);
+        x.clamp_assign(min, max);
+        assert_eq!(x, u6::new(10));
+
+        let mut x = u6::new(25);
+        x.clamp_assign(min, max);
+        assert_eq!(x, u6::new(25));
+
+        let mut x = u6::new(63);
+        x.clamp_assign(min, max);
+        assert_eq!(x, u6::new(40));
+
+        let mut x = u6(0b1100_0011);
+        x.clamp_assign(u6(0b0100_1010), max);
+        assert_eq!(x.0, 10);
+
+        let mut x = i6::new(-20);
+        x.clamp_assign(i6::new(-5), i6::new(5));
+        assert_eq!(x, i6::new(-5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clamp_assign_invalid_range() {
+        u6::new(3).clamp_assign(u6::new(40), u6::new(10));
+    }
+
+    #[test]
+    fn test_packed_flags() {
+        packed_flags! {
+            struct Access: u3 {
+                const READ = 0;
+                const WRITE = 1;
+                const EXECUTE = 2;
+            }
+        }
+
+        let mut access = Access::empty();
+        assert_eq!(access.bits(), u3::new(0));
+        access.set(Access::READ, true);
+        access.set(Access::EXECUTE, true);
+        assert!(access.get(Access::READ));
+        assert!(!access.get(Access::WRITE));
+        assert!(access.get(Access::EXECUTE));
+        assert_eq!(access.bits(), u3::new(0b101));
+
+        access.toggle(Access::WRITE);
+        access.set(Access::READ, false);
+        assert_eq!(access.bits(), u3::new(0b110));
+        access.toggle(Access::WRITE);
+        assert_eq!(access.bits(), u3::new(0b100));
+
+        for bits in 0..8 {
+            let access = Access::from_bits(u3::new(bits));
+            assert_eq!(access.bits(), u3::new(bits));
+            assert_eq!(access.get(Access::WRITE), bits & 0b010 != 0);
+            assert_eq!(Access::from_bits(access.bits()), access);
+        }
+    }
+
+    #[test]
+    fn test_is_aligned_to() {
+        assert!(u12::new(0b1010_1000).is_aligned_to(3));
+        assert!(!u12::new(0b1010_1100).is_aligned_to(3));
+        assert!(u12::new(0b1010_1100).is_aligned_to(2));
+        assert!(u12::new(1).is_aligned_to(0));
+        assert!(u12::new(0).is_aligned_to(12));
+        assert!(!u12::new(0x800).is_aligned_to(12));
+        assert!(u12(0xf800).is_aligned_to(11));
+        assert!(i12::MIN.is_aligned_to(11));
+        assert!(!i12::new(-1).is_aligned_to(1));
+        assert!(u127::new(1 << 126).is_aligned_to(126));
+    }
+
+    #[test]
+    #[should_panic(expected = "alignment out of bounds")]
+    fn test_is_aligned_to_out_of_bounds() {
+        let _ = u12::new(0).is_aligned_to(13);
+    }
+
+    #[test]
+    fn test_split_at_bit() {
+        let x = u12::new(0xabc);
+        assert_eq!(x.split_at_bit(4), (u12::new(0xc), u12::new(0xab)));
+        assert_eq!(x.split_at_bit(0), (u12::new(0), x));
+        assert_eq!(x.split_at_bit(12), (x, u12::new(0)));
+        assert_eq!(u12(0xfabc).split_at_bit(4), (u12::new(0xc), u12::new(0xab)));
+
+        let (low, high) = x.split_at_bit(5);
+        assert_eq!(low | high << 5, x);
+
+        assert_eq!(
+            i12::new(-1).split_at_bit(4),
+            (i12::new(0xf), i12::new(0xff))
+        );
+        assert_eq!(
+            u127::MAX.split_at_bit(64),
+            (
+                u127::new(u64::MAX as u128),
+                u127::new(u64::MAX as u128 >> 1)
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "bit index out of bounds")]
+    fn test_split_at_bit_out_of_bounds() {
+        let _ = u12::new(0).split_at_bit(13);
+    }
+
+    #[test]
+    fn test_to_canonical_primitive_unsigned() {
+        assert_eq!(i4::new(-1).to_canonical_primitive_unsigned(), 0b1111u8);
+        assert_eq!(i4::MIN.to_canonical_primitive_unsigned(), 0b1000u8);
+        assert_eq!(i4::new(5).to_canonical_primitive_unsigned(), 0b0101u8);
+        assert_eq!(i4(0b0111_1110).to_canonical_primitive_unsigned(), 0b1110u8);
+        assert_eq!(i1::new(-1).to_canonical_primitive_unsigned(), 1u8);
+        assert_eq!(i12::new(-2).to_canonical_primitive_unsigned(), 0xffeu16);
+        assert_eq!(i31::MIN.to_canonical_primitive_unsigned(), 1u32 << 30);
+        assert_eq!(
+            i40::new(-1).to_canonical_primitive_unsigned(),
+            (1u64 << 40) - 1
+        );
+        assert_eq!(i40::MAX.to_canonical_primitive_unsigned(), (1u64 << 39) - 1);
+        assert_eq!(
+            i127::new(-1).to_canonical_primitive_unsigned(),
+            u128::MAX >> 1
+        );
+        assert_eq!(i127::new(3).to_canonical_primitive_unsigned(), 3u128);
+    }
+
+    #[test]
+    fn test_swap_bytes() {
+        assert_eq!(u24::new(0x123456).swap_bytes(), u24::new(0x563412));
+        assert_eq!(u24::new(0x0000ff).swap_bytes(), u24::new(0xff0000));
+        assert_eq!(u24(0xab12_3456).swap_bytes(), u24::new(0x563412));
+        assert_eq!(
+            u40::new(0x12_3456_789a).swap_bytes(),
+            u40::new(0x9a_7856_3412)
+        );
+        assert_eq!(
+            u56::new(0x12_3456_789a_bcde).swap_bytes(),
+            u56::new(0xde_bc9a_7856_3412)
+        );
+        assert_eq!(u120::new(1).swap_bytes(), u120::new(1 << 112));
+        assert_eq!(i24::new(-1).swap_bytes(), i24::new(-1));
+        assert_eq!(i24::new(0x80).swap_bytes(), i24::MIN);
+        assert_eq!(i24::MIN.swap_bytes(), i24::new(0x80));
+        assert_eq!(i48::new(0x7f).swap_bytes(), i48::new(0x7f << 40));
+    }
+
+    #[test]
+    fn test_swap_nibbles() {
+        assert_eq!(u24::new(0x123456).swap_nibbles(), u24::new(0x214365));
+        assert_eq!(u24::new(0x0000f0).swap_nibbles(), u24::new(0x00000f));
+        assert_eq!(u24(0xab12_3456).swap_nibbles(), u24::new(0x214365));
+        assert_eq!(
+            u48::new(0x1234_5678_9abc).swap_nibbles(),
+            u48::new(0x2143_6587_a9cb)
+        );
+        assert_eq!(u120::MAX.swap_nibbles(), u120::MAX);
+        assert_eq!(i24::new(-1).swap_nibbles(), i24::new(-1));
+        assert_eq!(i24::new(0x0f).swap_nibbles(), i24::new(0xf0));
+        assert_eq!(
+            i24::new(0x08_0000).swap_nibbles(),
+            i24::new(0x80_0000 - 0x100_0000)
+        );
+
+        for x in 0..1 << 16 {
+            let x = u24::new(x * 199);
+            assert_eq!(x.swap_nibbles().swap_nibbles(), x);
+        }
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(u5::new(3).mod_inverse(u5::new(7)), Some(u5::new(5)));
+        assert_eq!(u5::new(10).mod_inverse(u5::new(7)), Some(u5::new(5)));
+        assert_eq!(u5::new(6).mod_inverse(u5::new(9)), None);
+        assert_eq!(u5::new(0).mod_inverse(u5::new(9)), None);
+        assert_eq!(u5::new(4).mod_inverse(u5::new(0)), None);
+        assert_eq!(u5::new(4).mod_inverse(u5::new(1)), Some(u5::new(0)));
+        assert_eq!(u5(0b1110_0011).mod_inverse(u5::new(7)), Some(u5::new(5)));
+
+        for m in 2..256 {
+            for a in 0..256 {
+                let (a, m) = (u9::new(a), u9::new(m));
+                match a.mod_inverse(m) {
+                    Some(x) => assert_eq!(a.0 as u32 * x.0 as u32 % m.0 as u32, 1),
+                    None => assert!((1..m.0).all(|x| a.0 as u32 * x as u32 % m.0 as u32 != 1)),
+                }
+            }
+        }
 
-define_signed!(#[doc="The 121-bit signed integer type."], i121, 121, i128);
-define_signed!(#[doc="The 122-bit signed integer type."], i122, 122, i128);
-define_signed!(#[doc="The 123-bit signed integer type."], i123, 123, i128);
-define_signed!(#[doc="The 124-bit signed integer type."], i124, 124, i128);
-define_signed!(#[doc="The 125-bit signed integer type."], i125, 125, i128);
-define_signed!(#[doc="The 126-bit signed integer type."], i126, 126, i128);
-define_signed!(#[doc="The 127-bit signed integer type."], i127, 127, i128);
+        let m = u127::MAX;
+        let x = u127::new(12345).mod_inverse(m).unwrap();
+        assert_eq!(x.mod_inverse(m), Some(u127::new(12345)));
+        assert_eq!(u127::new(2).mod_inverse(u127::new(1 << 100)), None);
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_mask_const() {
+        assert_eq!(u1::MASK, 0b1u8);
+        assert_eq!(u5::MASK, 0b11111u8);
+        assert_eq!(u31::MASK, 0x7fff_ffffu32);
+        assert_eq!(u33::MASK, 0x1_ffff_ffffu64);
+        assert_eq!(u127::MASK, u128::MAX >> 1);
+        assert_eq!(i5::MASK, 0b11111i8);
+        assert_eq!(i31::MASK, 0x7fff_ffffi32);
+        assert_eq!(i127::MASK, i128::MAX);
+        assert_eq!(u12(0xfabc).mask().0, 0xfabc & u12::MASK);
+    }
 
     #[test]
-    fn test_masking() {
-        assert_eq!(u4(0b11000110).mask().0, 0b00000110);
-        assert_eq!(u4(0b00001000).mask().0, 0b00001000);
-        assert_eq!(u4(0b00001110).mask().0, 0b00001110);
+    fn test_to_enum() {
+        #[derive(Debug, PartialEq)]
+        enum Opcode {
+            Load,
+            Store,
+            Jump,
+        }
 
-        assert_eq!(i4(0b11000110u8 as i8).mask().0, 0b00000110u8 as i8);
-        assert_eq!(i4(0b00001000u8 as i8).mask().0, 0b11111000u8 as i8);
-        assert_eq!(i4(0b00001110u8 as i8).mask().0, 0b11111110u8 as i8);
+        impl TryFrom<u8> for Opcode {
+            type Error = u8;
+
+            fn try_from(value: u8) -> Result<Self, Self::Error> {
+                match value {
+                    0 => Ok(Opcode::Load),
+                    1 => Ok(Opcode::Store),
+                    5 => Ok(Opcode::Jump),
+                    invalid => Err(invalid),
+                }
+            }
+        }
+
+        assert_eq!(u3::new(0).to_enum::<Opcode>(), Ok(Opcode::Load));
+        assert_eq!(u3::new(1).to_enum::<Opcode>(), Ok(Opcode::Store));
+        assert_eq!(u3(0b1111_0101).to_enum::<Opcode>(), Ok(Opcode::Jump));
+        assert_eq!(u3::new(7).to_enum::<Opcode>(), Err(7));
     }
 
     #[test]
-    fn min_max_values() {
-        assert_eq!(u1::MAX, u1(1));
-        assert_eq!(u2::MAX, u2(3));
-        assert_eq!(u3::MAX, u3(7));
-        assert_eq!(u7::MAX, u7(127));
-        assert_eq!(u9::MAX, u9(511));
+    fn test_saturating_shl() {
+        assert_eq!(u5::new(0b00011).saturating_shl(3), u5::new(0b11000));
+        assert_eq!(u5::new(0b00011).saturating_shl(4), u5::MAX);
+        assert_eq!(u5::new(1).saturating_shl(4), u5::new(0b10000));
+        assert_eq!(u5::new(1).saturating_shl(5), u5::MAX);
+        assert_eq!(u5::new(0).saturating_shl(100), u5::new(0));
+        assert_eq!(u5(0b1110_0001).saturating_shl(4), u5::new(0b10000));
+        assert_eq!(u127::new(1).saturating_shl(126), u127::new(1 << 126));
+        assert_eq!(u127::new(2).saturating_shl(126), u127::MAX);
+
+        assert_eq!(i5::new(3).saturating_shl(2), i5::new(12));
+        assert_eq!(i5::new(3).saturating_shl(3), i5::MAX);
+        assert_eq!(i5::new(1).saturating_shl(4), i5::MAX);
+        assert_eq!(i5::new(-2).saturating_shl(3), i5::MIN);
+        assert_eq!(i5::new(-2).saturating_shl(2), i5::new(-8));
+        assert_eq!(i5::new(-3).saturating_shl(3), i5::MIN);
+        assert_eq!(i5::new(-1).saturating_shl(5), i5::MIN);
+        assert_eq!(i127::new(-1).saturating_shl(126), i127::MIN);
+        assert_eq!(i127::new(1).saturating_shl(126), i127::MAX);
+    }
 
-        assert_eq!(i1::MAX, i1(0));
-        assert_eq!(i2::MAX, i2(1));
-        assert_eq!(i3::MAX, i3(3));
-        assert_eq!(i7::MAX, i7(63));
-        assert_eq!(i9::MAX, i9(255));
+    #[test]
+    fn test_set_bits() {
+        assert!(u4::new(0b1001).set_bits().eq([0, 3]));
+        assert_eq!(u4::new(0).set_bits().next(), None);
+        assert!(u4::new(0b0100).set_bits().eq([2]));
+        assert!(u4::MAX.set_bits().eq(0..4));
+        assert!(u4(0b1111_0010).set_bits().eq([1]));
+        assert!(u127::MAX.set_bits().eq(0..127));
+        assert!(u127::new(1 << 126).set_bits().eq([126]));
+        assert!(i4::new(-1).set_bits().eq(0..4));
+        assert!(i4::MIN.set_bits().eq([3]));
+        assert!(i127::MIN.set_bits().eq([126]));
+    }
 
-        assert_eq!(u1::MIN, u1(0));
-        assert_eq!(u2::MIN, u2(0));
-        assert_eq!(u3::MIN, u3(0));
-        assert_eq!(u7::MIN, u7(0));
-        assert_eq!(u9::MIN, u9(0));
-        assert_eq!(u127::MIN, u127(0));
+    #[cfg(feature = "std")]
+    fn panic_message<F: FnOnce() + std::panic::UnwindSafe>(f: F) -> String {
+        let payload = std::panic::catch_unwind(f).expect_err("expected a panic");
+        match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(payload) => *payload.downcast::<String>().unwrap(),
+        }
+    }
 
-        assert_eq!(i1::MIN, i1(-1));
-        assert_eq!(i2::MIN, i2(-2));
-        assert_eq!(i3::MIN, i3(-4));
-        assert_eq!(i7::MIN, i7(-64));
-        assert_eq!(i9::MIN, i9(-256));
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_div_rem_panics() {
+        let (a, zero, minus_one) = (i6::new(17), i6::new(0), i6::new(-1));
+        assert_eq!(
+            panic_message(|| {
+                let _ = a / zero;
+            }),
+            "attempt to divide by zero"
+        );
+        assert_eq!(
+            panic_message(|| {
+                let _ = a % zero;
+            }),
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+        assert_eq!(
+            panic_message(|| {
+                let _ = i6::MIN / minus_one;
+            }),
+            "attempt to divide with overflow"
+        );
+        assert_eq!(
+            panic_message(|| {
+                let _ = i6::MIN % minus_one;
+            }),
+            "attempt to calculate the remainder with overflow"
+        );
+        assert_eq!(
+            panic_message(|| {
+                let _ = u6::new(1) / u6(0b1100_0000);
+            }),
+            "attempt to divide by zero"
+        );
+
+        assert_eq!(i6::MIN / i6::new(1), i6::MIN);
+        assert_eq!((i6::MIN + i6::new(1)) / minus_one, i6::MAX);
+        assert_eq!(a / i6::new(-5), i6::new(-3));
+        assert_eq!(a % i6::new(-5), i6::new(2));
+        assert_eq!(i127::MIN / i127::new(2), i127::new(-(1 << 125)));
     }
 
     #[test]
-    fn test_wrapping_add() {
-        assert_eq!(u1::MAX.wrapping_add(u1(1)), u1(0));
-        assert_eq!(u1::MAX.wrapping_add(u1(0)), u1(1));
+    fn test_reverse_bytes() {
+        assert_eq!(u12::new(0xabc).reverse_bytes(), u12::new(0xc0a));
+        assert_eq!(u12::new(0x30a).reverse_bytes(), u12::new(0xa03));
+        assert_eq!(u12::new(0xa03).reverse_bytes(), u12::new(0x30a));
+        assert_eq!(u12(0xf30a).reverse_bytes(), u12::new(0xa03));
+        assert_eq!(u20::new(0xabcde).reverse_bytes(), u20::new(0xebc0a));
+        assert_eq!(u20::new(0x1234).reverse_bytes(), u20::new(0x41200));
+        assert_eq!(u20::new(0x12305).reverse_bytes(), u20::new(0x52301));
+        assert_eq!(u20::new(0x52301).reverse_bytes(), u20::new(0x12305));
+        assert_eq!(u24::new(0x123456).reverse_bytes(), u24::new(0x563412));
+        assert_eq!(u5::new(0b10110).reverse_bytes(), u5::new(0b10110));
+        assert_eq!(u1::MAX.reverse_bytes(), u1::MAX);
+        assert_eq!(u127::MAX.reverse_bytes(), u127::new(u127::MAX.0 ^ 0x80));
+
+        assert_eq!(i12::new(-1).reverse_bytes(), i12::new(-0xf1));
+        assert_eq!(i12::new(0x30a).reverse_bytes(), i12::new(-0x5fd));
+        assert_eq!(i12::new(-0x5fd).reverse_bytes(), i12::new(0x30a));
+
+        for x in 0..1 << 12 {
+            let x = u12::new(x);
+            if x.0 & 0xf0 == 0 {
+                assert_eq!(x.reverse_bytes().reverse_bytes(), x);
+            }
+        }
+        for x in 0..1 << 16 {
+            let x = u24::new(x * 251);
+            assert_eq!(x.reverse_bytes().reverse_bytes(), x);
+        }
+    }
 
-        assert_eq!(u5::MAX.wrapping_add(u5(1)), u5(0));
-        assert_eq!(u5::MAX.wrapping_add(u5(4)), u5(3));
+    #[test]
+    fn test_cmp_primitive() {
+        assert_eq!(u5::new(7).cmp_primitive(-1), Ordering::Greater);
+        assert_eq!(u5::new(7).cmp_primitive(0), Ordering::Greater);
+        assert_eq!(u5::new(7).cmp_primitive(7), Ordering::Equal);
+        assert_eq!(u5::new(7).cmp_primitive(8), Ordering::Less);
+        assert_eq!(u5::MAX.cmp_primitive(32), Ordering::Less);
+        assert_eq!(u5::MAX.cmp_primitive(i128::MAX), Ordering::Less);
+        assert_eq!(u5(0b1110_0111).cmp_primitive(7), Ordering::Equal);
+        assert_eq!(i5::MIN.cmp_primitive(-17), Ordering::Greater);
+        assert_eq!(i5::new(-3).cmp_primitive(-3), Ordering::Equal);
+        assert_eq!(i5::MIN.cmp_primitive(i128::MIN), Ordering::Greater);
+        assert_eq!(u127::MAX.cmp_primitive(i128::MAX), Ordering::Equal);
+        assert_eq!(i127::MAX.cmp_primitive(1 << 126), Ordering::Less);
+    }
 
-        assert_eq!(u127::MAX.wrapping_add(u127(100)), u127(99));
-        assert_eq!(u127::MAX.wrapping_add(u127(1)), u127(0));
+    #[test]
+    fn test_bit_width() {
+        assert_eq!(u7::new(0).bit_width(), 0);
+        assert_eq!(u7::new(1).bit_width(), 1);
+        assert_eq!(u7::new(5).bit_width(), 3);
+        assert_eq!(u7::MAX.bit_width(), 7);
+        assert_eq!(u7(0b1000_0101).bit_width(), 3);
+        assert_eq!(u1::MAX.bit_width(), 1);
+        assert_eq!(u127::MAX.bit_width(), 127);
+        assert_eq!(u127::new(1 << 100).bit_width(), 101);
+
+        assert_eq!(i7::new(0).bit_width(), 0);
+        assert_eq!(i7::new(-1).bit_width(), 0);
+        assert_eq!(i7::new(5).bit_width(), 3);
+        assert_eq!(i7::new(-6).bit_width(), 3);
+        assert_eq!(i7::MAX.bit_width(), 6);
+        assert_eq!(i7::MIN.bit_width(), 6);
+        assert_eq!(i7(0b0111_1111).bit_width(), 0);
+        assert_eq!(i1::MIN.bit_width(), 0);
+        assert_eq!(i127::MIN.bit_width(), 126);
+    }
 
-        assert_eq!(i1::MAX.wrapping_add(i1(0)), i1(0));
-        assert_eq!(i1::MAX.wrapping_add(i1(-1)), i1(-1));
+    #[test]
+    fn test_saturating_from() {
+        assert_eq!(u5::saturating_from(i128::MAX), u5::MAX);
+        assert_eq!(u5::saturating_from(i128::MIN), u5::MIN);
+        assert_eq!(u5::saturating_from(17i8), u5::new(17));
+        assert_eq!(u5::saturating_from(-1i32), u5::new(0));
+        assert_eq!(u5::saturating_from(32u8), u5::MAX);
+        assert_eq!(i5::saturating_from(i128::MAX), i5::MAX);
+        assert_eq!(i5::saturating_from(i128::MIN), i5::MIN);
+        assert_eq!(i5::saturating_from(-7i64), i5::new(-7));
+        assert_eq!(i5::saturating_from(16u16), i5::MAX);
+        assert_eq!(u127::saturating_from(i128::MAX), u127::MAX);
+        assert_eq!(i127::saturating_from(i128::MIN), i127::MIN);
+
+        assert_eq!(u5::saturating_from_unsigned(u128::MAX), u5::MAX);
+        assert_eq!(u5::saturating_from_unsigned(9u64), u5::new(9));
+        assert_eq!(i5::saturating_from_unsigned(u128::MAX), i5::MAX);
+        assert_eq!(i5::saturating_from_unsigned(0u64), i5::new(0));
+        assert_eq!(u127::saturating_from_unsigned(u128::MAX), u127::MAX);
+    }
 
-        assert_eq!(i7::MAX.wrapping_add(i7(1)), i7::MIN);
-        assert_eq!(i7::MAX.wrapping_add(i7(4)), i7(-61));
+    #[test]
+    fn test_wrapping_accumulate() {
+        let mut timer = u7::new(120);
+        assert!(!timer.wrapping_accumulate(u7::new(5)));
+        assert_eq!(timer, u7::new(125));
+        assert!(!timer.wrapping_accumulate(u7::new(2)));
+        assert_eq!(timer, u7::MAX);
+        assert!(timer.wrapping_accumulate(u7::new(1)));
+        assert_eq!(timer, u7::new(0));
+        assert!(!timer.wrapping_accumulate(u7::new(0)));
+
+        let mut timer = u7::new(100);
+        assert!(timer.wrapping_accumulate(u7::new(100)));
+        assert_eq!(timer, u7::new(72));
+
+        let mut counter = i7::MAX;
+        assert!(counter.wrapping_accumulate(i7::new(1)));
+        assert_eq!(counter, i7::MIN);
+        assert!(counter.wrapping_accumulate(i7::new(-1)));
+        assert_eq!(counter, i7::MAX);
     }
 
     #[test]
-    #[should_panic]
-    fn test_add_overflow_u5() {
-        let _s = u5::MAX + u5(1);
+    fn test_saturating_cast() {
+        assert_eq!(i5::new(-3).saturating_cast::<u3>(), u3::new(0));
+        assert_eq!(i5::MIN.saturating_cast::<u127>(), u127::new(0));
+        assert_eq!(i127::MIN.saturating_cast::<u1>(), u1::new(0));
+        assert_eq!(u12::new(300).saturating_cast::<u4>(), u4::MAX);
+        assert_eq!(u12::new(300).saturating_cast::<i4>(), i4::MAX);
+        assert_eq!(i12::new(-300).saturating_cast::<i4>(), i4::MIN);
+        assert_eq!(u127::MAX.saturating_cast::<i127>(), i127::MAX);
+        assert_eq!(u127::MAX.saturating_cast::<u63>(), u63::MAX);
+
+        assert_eq!(u12::new(9).saturating_cast::<u4>(), u4::new(9));
+        assert_eq!(i5::new(-3).saturating_cast::<i127>(), i127::new(-3));
+        assert_eq!(u4::MAX.saturating_cast::<i5>(), i5::MAX);
+        assert_eq!(u4(0b1111_0011).saturating_cast::<u2>(), u2::MAX);
+        assert_eq!(i4::new(7).saturating_cast::<u3>(), u3::new(7));
     }
 
     #[test]
-    #[should_panic]
-    fn test_add_overflow_u127() {
-        let _s = u127::MAX + u127(1);
+    fn test_overflowing_add_dirty_operands() {
+        // The backing u8 has bit 7 set, which is not part of the u7 value.
+        assert_eq!(
+            u7(0b1000_0001).overflowing_add(u7(0b1000_0001)),
+            (u7::new(2), false)
+        );
+        assert_eq!(
+            u7(0b1100_0000).overflowing_add(u7(0b1100_0000)),
+            (u7::new(0), true)
+        );
+        assert_eq!(
+            u7(0b1111_1111).overflowing_add(u7::new(0)),
+            (u7::MAX, false)
+        );
+        assert_eq!(
+            u7(0b1111_1111).overflowing_add(u7(0b1000_0001)),
+            (u7::new(0), true)
+        );
+        assert_eq!(u7(0b1000_0000).checked_add(u7(0b1111_1111)), Some(u7::MAX));
+
+        assert_eq!(
+            u63(u64::MAX).overflowing_add(u63(1 << 63)),
+            (u63::MAX, false)
+        );
+        assert_eq!(
+            u63(u64::MAX).overflowing_add(u63(u64::MAX)),
+            (u63::MAX - u63::new(1), true)
+        );
+        assert_eq!(
+            u127(u128::MAX).overflowing_add(u127(1)),
+            (u127::new(0), true)
+        );
+
+        // The eighth bit of the backing i8 need not match the sign bit of the i7 value.
+        assert_eq!(
+            i7(0b0111_1111).overflowing_add(i7(0b0111_1111)),
+            (i7::new(-2), false)
+        );
+        assert_eq!(
+            i7(0b0100_0000).overflowing_add(i7(0b0100_0000)),
+            (i7::new(0), true)
+        );
+        assert_eq!(
+            i7(0b1011_1111u8 as i8).overflowing_add(i7(0b1000_0001u8 as i8)),
+            (i7::MIN, true)
+        );
+        assert_eq!(
+            i7(0b1011_1111u8 as i8).overflowing_add(i7::new(-1)),
+            (i7::new(62), false)
+        );
+
+        for a in 0..=255u8 {
+            for b in 0..=255u8 {
+                let sum = (a & 0x7f) + (b & 0x7f);
+                assert_eq!(
+                    u7(a).overflowing_add(u7(b)),
+                    (u7::new(sum & 0x7f), sum > 0x7f)
+                );
+            }
+        }
     }
 
     #[test]
-    #[should_panic]
-    fn test_add_overflow_i96() {
-        let _s = i96::MAX + i96(100);
+    fn test_checked_next_power_of_two() {
+        assert_eq!(u5::new(17).checked_next_power_of_two(), None);
+        assert_eq!(u5::new(16).checked_next_power_of_two(), Some(u5::new(16)));
+        assert_eq!(u5::new(15).checked_next_power_of_two(), Some(u5::new(16)));
+        assert_eq!(u5::MAX.checked_next_power_of_two(), None);
+        assert_eq!(u5::new(0).checked_next_power_of_two(), Some(u5::new(1)));
+        assert_eq!(u5::new(1).checked_next_power_of_two(), Some(u5::new(1)));
+        assert_eq!(
+            u5(0b1110_0011).checked_next_power_of_two(),
+            Some(u5::new(4))
+        );
+        assert_eq!(u1::new(1).checked_next_power_of_two(), Some(u1::new(1)));
+        assert_eq!(u7::new(65).checked_next_power_of_two(), None);
+        assert_eq!(
+            u127::new(1 << 126).checked_next_power_of_two(),
+            Some(u127::new(1 << 126))
+        );
+        assert_eq!(u127::new((1 << 126) + 1).checked_next_power_of_two(), None);
+
+        assert_eq!(u5::new(9).next_power_of_two(), u5::new(16));
+        assert!(u5::new(16).is_power_of_two());
+        assert!(!u5::new(0).is_power_of_two());
+        assert!(!u5(0b0110_0000).is_power_of_two());
     }
 
+    #[cfg(debug_assertions)]
     #[test]
-    #[should_panic]
-    fn test_add_underflow_i96() {
-        let _s = i96::MIN + i96(-100);
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_next_power_of_two_overflow() {
+        let _ = u5::new(17).next_power_of_two();
     }
 
     #[test]
-    #[should_panic]
-    fn test_add_underflow_i17() {
-        let _s = i17::MIN + i17(-1);
+    fn test_distance_to_max_min() {
+        assert_eq!(u5::new(20).distance_to_max(), u5::new(11));
+        assert_eq!(u5::MAX.distance_to_max(), u5::new(0));
+        assert_eq!(u5::MIN.distance_to_max(), u5::MAX);
+        assert_eq!(u5(0b1111_1110).distance_to_max(), u5::new(1));
+        assert_eq!(u127::MAX.distance_to_max(), u127::new(0));
+
+        assert_eq!(u5::new(20).distance_to_min(), u5::new(20));
+        assert_eq!(u5::MIN.distance_to_min(), u5::new(0));
+        assert_eq!(u5::MAX.distance_to_min(), u5::MAX);
+        assert_eq!(u5(0b1110_0001).distance_to_min(), u5::new(1));
     }
 
     #[test]
-    fn test_add() {
-        assert_eq!(u5(1) + u5(2), u5(3));
+    fn test_from_fn() {
+        assert_eq!(u4::from_fn(|i| i % 2 == 0), u4::new(0b0101));
+        assert_eq!(u4::from_fn(|_| true), u4::MAX);
+        assert_eq!(u4::from_fn(|_| false), u4::new(0));
+        assert_eq!(u127::from_fn(|_| true), u127::MAX);
+        assert_eq!(
+            u63::from_fn(|i| i % 2 == 1),
+            u63::new(0x2aaa_aaaa_aaaa_aaaa)
+        );
+        assert_eq!(i4::from_fn(|_| true), i4::new(-1));
+        assert_eq!(i4::from_fn(|i| i == 3), i4::MIN);
+        assert_eq!(i127::from_fn(|i| i < 126), i127::MAX);
+
+        let mut calls = 0;
+        let x = u12::from_fn(|i| {
+            assert_eq!(i, calls);
+            calls += 1;
+            false
+        });
+        assert_eq!((x, calls), (u12::new(0), 12));
+    }
 
-        assert_eq!(i7::MAX + i7::MIN, i7(-1));
-        assert_eq!(i7(4) + i7(-3), i7(1));
-        assert_eq!(i7(-4) + i7(3), i7(-1));
-        assert_eq!(i7(-3) + i7(-20), i7(-23));
+    #[test]
+    fn test_abs_wide_signed() {
+        assert_eq!(i40::MIN.checked_abs(), None);
+        assert_eq!(i40::MIN.wrapping_abs(), i40::MIN);
+        assert_eq!(i40::MIN.saturating_abs(), i40::MAX);
+        assert_eq!(i40::MIN.unsigned_abs(), u40::new(1 << 39));
+        assert_eq!(i40::MAX.abs(), i40::MAX);
+        assert_eq!(i40::MAX.unsigned_abs(), u40::new((1 << 39) - 1));
+        assert_eq!((i40::MIN + i40::new(1)).abs(), i40::MAX);
+        assert_eq!(i40::new(-1).checked_abs(), Some(i40::new(1)));
+        assert_eq!(i40(i40::MIN.0 ^ (1 << 50)).checked_abs(), None);
+
+        assert_eq!(i33::MIN.unsigned_abs(), u33::new(1 << 32));
+        assert_eq!(i33::MIN.checked_abs(), None);
+        assert_eq!(i63::MIN.unsigned_abs(), u63::new(1 << 62));
+        assert_eq!(i63::MIN.saturating_abs(), i63::MAX);
+        assert_eq!(i63::MIN.wrapping_abs(), i63::MIN);
+        assert_eq!(i63::new(-5).wrapping_abs(), i63::new(5));
+
+        assert_eq!(i1::MIN.checked_abs(), None);
+        assert_eq!(i1::MIN.unsigned_abs(), u1::new(1));
+        assert_eq!(i127::MIN.unsigned_abs(), u127::new(1 << 126));
+        assert_eq!(i127::MIN.saturating_abs(), i127::MAX);
     }
 
+    #[cfg(debug_assertions)]
     #[test]
-    #[should_panic]
-    fn test_sub_overflow_i23() {
-        let _s = i23::MIN - i23::MAX;
+    #[should_panic(expected = "attempt to negate with overflow")]
+    fn test_abs_min_overflow() {
+        let _ = i40::MIN.abs();
     }
 
     #[test]
-    #[should_panic]
-    fn test_sub_underflow_u5() {
-        let _s = u5::MIN - u5(1);
+    fn test_signum_and_sign_predicates() {
+        assert_eq!(i6::new(-10).abs(), i6::new(10));
+        assert_eq!(i6::new(10).abs(), i6::new(10));
+
+        assert_eq!(i6::new(0).signum(), i6::new(0));
+        assert_eq!(i6::new(-10).signum(), i6::new(-1));
+        assert_eq!(i6::MAX.signum(), i6::new(1));
+        assert_eq!(i6::MIN.signum(), i6::new(-1));
+        assert_eq!(i6(0b0100_0101).signum(), i6::new(1));
+        assert_eq!(i6(0b0010_0000).signum(), i6::new(-1));
+        assert_eq!(i1::new(-1).signum(), i1::new(-1));
+        assert_eq!(i127::MIN.signum(), i127::new(-1));
+
+        assert!(i6::new(1).is_positive());
+        assert!(!i6::new(1).is_negative());
+        assert!(i6::new(-1).is_negative());
+        assert!(!i6::new(-1).is_positive());
+        assert!(!i6::new(0).is_positive());
+        assert!(!i6::new(0).is_negative());
+        assert!(i6::MIN.is_negative());
+        assert!(i6(0b0010_0000).is_negative());
+        assert!(i6(0b1100_0001u8 as i8).is_positive());
+        assert!(i1::new(-1).is_negative());
     }
 
     #[test]
-    #[should_panic]
-    fn test_sub_underflow_i5() {
-        let _s = i5::MIN - i5(1);
+    fn test_pack_into_u64() {
+        let values = [
+            u12::new(0x12),
+            u12::new(0x34),
+            u12::new(0x56),
+            u12::new(0x78),
+        ];
+        assert_eq!(u12::pack_into_u64(&values), 0x078_056_034_012);
+        assert_eq!(u7::pack_into_u64(&[]), 0);
+        assert_eq!(u7::pack_into_u64(&[u7::new(1), u7::new(1)]), 0b1_0000001);
+        assert_eq!(i4::pack_into_u64(&[i4::new(-1), i4::new(2)]), 0x2f);
+        assert_eq!(u63::pack_into_u64(&[u63::MAX]), u64::MAX >> 1);
     }
 
     #[test]
-    fn test_sub() {
-        assert_eq!(u5(1) - u5(1), u5(0));
-        assert_eq!(u5(3) - u5(2), u5(1));
+    #[should_panic(expected = "too many values to pack into a u64")]
+    fn test_pack_into_u64_too_many() {
+        let _ = u13::pack_into_u64(&[u13::new(0); 5]);
+    }
 
-        assert_eq!(i1(-1) - i1(-1), i1(0));
-        assert_eq!(i7::MIN - i7::MIN, i7(0));
-        assert_eq!(i7(4) - i7(-3), i7(7));
-        assert_eq!(i7(-4) - i7(3), i7(-7));
-        assert_eq!(i7(-3) - i7(-20), i7(17));
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_unpack_from_u64() {
+        let values = vec![
+            u15::new(0x12),
+            u15::new(0x7fff),
+            u15::new(0),
+            u15::new(0x7f),
+        ];
+        assert_eq!(u15::unpack_from_u64(u15::pack_into_u64(&values)), values);
+
+        let values = vec![
+            u12::MAX,
+            u12::new(1),
+            u12::new(0),
+            u12::new(0xabc),
+            u12::new(7),
+        ];
+        assert_eq!(u12::unpack_from_u64(u12::pack_into_u64(&values)), values);
+
+        let values = vec![i21::MIN, i21::new(-1), i21::MAX];
+        assert_eq!(i21::unpack_from_u64(i21::pack_into_u64(&values)), values);
+
+        let packed = u13::pack_into_u64(&[u13::MAX]);
+        assert_eq!(
+            u13::unpack_from_u64(packed),
+            vec![u13::MAX, u13::new(0), u13::new(0), u13::new(0)]
+        );
+        assert_eq!(u1::unpack_from_u64(u64::MAX), vec![u1::new(1); 64]);
+        assert_eq!(i63::unpack_from_u64(u64::MAX), vec![i63::new(-1)]);
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn test_shr() {
-        assert_eq!(u5(8) >> 1usize, u5(4));
-        assert_eq!(u5(8) >> 1u8, u5(4));
-        assert_eq!(u5(8) >> 1u16, u5(4));
-        assert_eq!(u5(8) >> 1u32, u5(4));
-        assert_eq!(u5(8) >> 1u64, u5(4));
-        assert_eq!(u5(8) >> 1isize, u5(4));
-        assert_eq!(u5(8) >> 1i8, u5(4));
-        assert_eq!(u5(8) >> 1i16, u5(4));
-        assert_eq!(u5(8) >> 1i32, u5(4));
-        assert_eq!(u5(8) >> 1i64, u5(4));
+    fn test_pack_le_be() {
+        let values = [u3::new(0b001), u3::new(0b110), u3::new(0b111)];
+        assert_eq!(u3::pack_le(&values), vec![0b1111_0001, 0b1]);
+        assert_eq!(u3::pack_be(&values), vec![0b0011_1011, 0b1000_0000]);
+        assert_eq!(u3::unpack_le(&u3::pack_le(&values), 3), values);
+        assert_eq!(u3::unpack_be(&u3::pack_be(&values), 3), values);
+
+        let values = [u12::new(0xabc), u12::new(0x123), u12::MAX, u12::new(0)];
+        assert_eq!(
+            u12::pack_le(&values),
+            vec![0xbc, 0x3a, 0x12, 0xff, 0x0f, 0x00]
+        );
+        assert_eq!(
+            u12::pack_be(&values),
+            vec![0xab, 0xc1, 0x23, 0xff, 0xf0, 0x00]
+        );
+        assert_eq!(u12::unpack_le(&u12::pack_le(&values), 4), values);
+        assert_eq!(u12::unpack_be(&u12::pack_be(&values), 4), values);
+
+        let values = [i5::MIN, i5::new(-1), i5::new(7)];
+        assert_eq!(i5::unpack_le(&i5::pack_le(&values), 3), values);
+        assert_eq!(i5::unpack_be(&i5::pack_be(&values), 3), values);
+
+        let values = [u63::MAX, u63::new(1)];
+        assert_eq!(u63::unpack_le(&u63::pack_le(&values), 2), values);
+        assert_eq!(u63::unpack_be(&u63::pack_be(&values), 2), values);
+
+        assert_eq!(u3::pack_le(&[]), Vec::<u8>::new());
+        assert_eq!(u3::unpack_be(&[], 0), vec![]);
+    }
 
-        assert_eq!(u5::MAX >> 4, u5(1));
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic(expected = "not enough bytes to unpack the values")]
+    fn test_unpack_le_too_short() {
+        let _ = u12::unpack_le(&[0xff; 4], 3);
+    }
 
-        assert_eq!(i7(-1) >> 5, i7(-1));
+    #[cfg(not(feature = "strict-shifts"))]
+    #[test]
+    fn test_permissive_shifts() {
+        assert_eq!(u5::new(1) << 7, u5::new(0));
+        assert_eq!(u5::new(0b11111) << 7, u5::new(0));
+        assert_eq!(u5::new(0b11111) >> 7, u5::new(0));
+        assert_eq!(i5::new(-1) >> 7, i5::new(-1));
+
+        let mut x = u5::new(1);
+        x <<= 7;
+        assert_eq!(x, u5::new(0));
     }
 
+    #[cfg(all(feature = "strict-shifts", debug_assertions))]
     #[test]
-    fn test_shl() {
-        assert_eq!(u5(16) << 1usize, u5(32));
-        assert_eq!(u5(16) << 1u8, u5(32));
-        assert_eq!(u5(16) << 1u16, u5(32));
-        assert_eq!(u5(16) << 1u32, u5(32));
-        assert_eq!(u5(16) << 1u64, u5(32));
-        assert_eq!(u5(16) << 1isize, u5(32));
-        assert_eq!(u5(16) << 1i8, u5(32));
-        assert_eq!(u5(16) << 1i16, u5(32));
-        assert_eq!(u5(16) << 1i32, u5(32));
-        assert_eq!(u5(16) << 1i64, u5(32));
+    #[should_panic(expected = "attempt to shift left with overflow")]
+    fn test_strict_shl_overflow() {
+        let _ = u5::new(1) << 7;
+    }
 
-        assert_eq!(u5::MAX << 4, u5(16));
+    #[cfg(all(feature = "strict-shifts", debug_assertions))]
+    #[test]
+    #[should_panic(expected = "attempt to shift right with overflow")]
+    fn test_strict_shr_overflow() {
+        let _ = u5::new(1) >> 5u8;
+    }
 
-        assert_eq!(i5(16) << 1, i5(0));
-        assert_eq!(i7(1) << 3, i7(8));
+    #[cfg(all(feature = "strict-shifts", debug_assertions))]
+    #[test]
+    #[should_panic(expected = "attempt to shift left with overflow")]
+    fn test_strict_shl_assign_overflow() {
+        let mut x = u5::new(1);
+        x <<= 7;
     }
 
+    #[cfg(feature = "strict-shifts")]
     #[test]
-    fn test_shr_assign() {
-        let mut x = u10(512);
-        x >>= 1usize;
-        assert_eq!(x, u10(256));
-        x >>= 1isize;
-        assert_eq!(x, u10(128));
-        x >>= 1u8;
-        assert_eq!(x, u10(64));
-        x >>= 1i8;
-        assert_eq!(x, u10(32));
-        x >>= 2u64;
-        assert_eq!(x, u10(8));
-        x >>= 3i32;
-        assert_eq!(x, u10(1));
+    fn test_strict_shifts_in_range() {
+        assert_eq!(u5::new(1) << 4, u5::new(0b10000));
+        assert_eq!(u5::new(0b10000) >> 4usize, u5::new(1));
+        assert_eq!(i5::new(-16) >> 4i32, i5::new(-1));
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn test_shl_assign() {
-        let mut x = u9(1);
-        x <<= 3i32;
-        assert_eq!(x, u9(8));
-        x <<= 2u64;
-        assert_eq!(x, u9(32));
-        x <<= 1usize;
-        assert_eq!(x, u9(64));
-        x <<= 1isize;
-        assert_eq!(x, u9(128));
-        x <<= 1u8;
-        assert_eq!(x, u9(256));
+    fn test_to_string_radix() {
+        assert_eq!(u5::new(0b10110).to_string_radix(2), "10110");
+        assert_eq!(u5::new(0).to_string_radix(2), "0");
+        assert_eq!(u7::new(100).to_string_radix(3), "10201");
+        assert_eq!(u12::MAX.to_string_radix(10), "4095");
+        assert_eq!(u12::MAX.to_string_radix(16), "fff");
+        assert_eq!(u12::MAX.to_string_radix(36), "35r");
+        assert_eq!(i12::new(-42).to_string_radix(10), "-42");
+        assert_eq!(i12::new(-42).to_string_radix(16), "-2a");
+        assert_eq!(i12::MIN.to_string_radix(2), "-100000000000");
+        assert_eq!(
+            i127::MIN.to_string_radix(16),
+            format!("-{:x}", 1u128 << 126)
+        );
+        assert_eq!(u127::MAX.to_string_radix(10), u127::MAX.to_string());
     }
 
     #[test]
-    fn test_bitor() {
-        assert_eq!(u9(1) | u9(8), u9(9));
-        assert_eq!(&u9(1) | u9(8), u9(9));
-        assert_eq!(u9(1) | &u9(8), u9(9));
-        assert_eq!(&u9(1) | &u9(8), u9(9));
+    fn test_leading_trailing_zeros() {
+        assert_eq!(u4::new(1).leading_zeros(), 3);
+        assert_eq!(u4::new(0).leading_zeros(), 4);
+        assert_eq!(u4::new(0).trailing_zeros(), 4);
+        assert_eq!(u4::new(0b1000).trailing_zeros(), 3);
+        assert_eq!(u4::MAX.leading_zeros(), 0);
+        assert_eq!(u4(0b1111_0010).leading_zeros(), 2);
+        assert_eq!(u4(0b1111_0000).trailing_zeros(), 4);
+
+        assert_eq!(u12::new(0x0f0).leading_zeros(), 4);
+        assert_eq!(u12::new(0x0f0).trailing_zeros(), 4);
+        assert_eq!(u12::new(0).leading_zeros(), 12);
+        assert_eq!(u12::new(0).trailing_zeros(), 12);
+        assert_eq!(u12::new(0x800).leading_zeros(), 0);
+        assert_eq!(u12::new(0x800).trailing_zeros(), 11);
+
+        assert_eq!(i6::new(-1).leading_zeros(), 0);
+        assert_eq!(i6::new(-1).trailing_zeros(), 0);
+        assert_eq!(i6::MIN.leading_zeros(), 0);
+        assert_eq!(i6::MIN.trailing_zeros(), 5);
+        assert_eq!(i6::new(1).leading_zeros(), 5);
+        assert_eq!(i6::new(0).leading_zeros(), 6);
+        assert_eq!(i6::new(0).trailing_zeros(), 6);
+        assert_eq!(i6::new(-4).trailing_zeros(), 2);
+
+        assert_eq!(u127::new(1).leading_zeros(), 126);
+        assert_eq!(i127::new(0).trailing_zeros(), 127);
+        assert_eq!(u1::new(0).leading_zeros(), 1);
     }
 
     #[test]
-    fn test_bitor_assign() {
-        let mut x = u12(4);
-        x |= u12(1);
-        assert_eq!(x, u12(5));
-        x |= u12(128);
-        assert_eq!(x, u12(133));
-        x = u12(1);
-        x |= u12(127);
-        assert_eq!(x, u12(127));
+    fn test_leading_trailing_ones() {
+        assert_eq!(u4::MAX.leading_ones(), 4);
+        assert_eq!(u4::MAX.trailing_ones(), 4);
+        assert_eq!(u4::new(0).leading_ones(), 0);
+        assert_eq!(u4::new(0).trailing_ones(), 0);
+        assert_eq!(u4::new(0b1101).leading_ones(), 2);
+        assert_eq!(u4::new(0b1011).trailing_ones(), 2);
+        assert_eq!(u4(0b1111_0111).leading_ones(), 0);
+        assert_eq!(u4(0b0011_0111).trailing_ones(), 3);
+        assert_eq!(u12::new(0xf0f).leading_ones(), 4);
+        assert_eq!(u127::MAX.leading_ones(), 127);
+
+        assert_eq!(i6::new(-1).leading_ones(), 6);
+        assert_eq!(i6::new(-1).trailing_ones(), 6);
+        assert_eq!(i6::MIN.leading_ones(), 1);
+        assert_eq!(i6::MIN.trailing_ones(), 0);
+        assert_eq!(i6::new(-4).leading_ones(), 4);
+        assert_eq!(i6::MAX.leading_ones(), 0);
+        assert_eq!(i6::MAX.trailing_ones(), 5);
+        assert_eq!(i127::new(-1).leading_ones(), 127);
+        assert_eq!(i1::new(-1).trailing_ones(), 1);
     }
 
     #[test]
-    fn test_bitxor() {
-        assert_eq!(u7(0x7F) ^ u7(42), u7(85));
-        assert_eq!(&u7(0) ^ u7(42), u7(42));
-        assert_eq!(u7(0x10) ^ &u7(0x1), u7(0x11));
-        assert_eq!(&u7(11) ^ &u7(1), u7(10));
+    fn test_count_ones_zeros_unsigned() {
+        assert_eq!(u4::new(0).count_zeros(), 4);
+        assert_eq!(u4::new(0).count_ones(), 0);
+        assert_eq!(u4::MAX.count_ones(), 4);
+        assert_eq!(u4::MAX.count_zeros(), 0);
+        assert_eq!(u12::new(0b1010_0000_0001).count_ones(), 3);
+        assert_eq!(u12::new(0b1010_0000_0001).count_zeros(), 9);
+        assert_eq!(u1::new(0).count_zeros(), 1);
+        assert_eq!(u63::MAX.count_ones(), 63);
+        assert_eq!(u127::new(0).count_zeros(), 127);
+        assert_eq!(u4(0b1111_0000).count_zeros(), 4);
     }
 
     #[test]
-    fn test_bitxor_assign() {
-        let mut x = u12(4);
-        x ^= u12(1);
-        assert_eq!(x, u12(5));
-        x ^= u12(128);
-        assert_eq!(x, u12(133));
-        x ^= u12(1);
-        assert_eq!(x, u12(132));
-        x ^= u12(127);
-        assert_eq!(x, u12(251));
+    fn test_count_ones_zeros_signed_negatives() {
+        assert_eq!(i4::new(-1).count_ones(), 4);
+        assert_eq!(i4::new(-1).count_zeros(), 0);
+        assert_eq!(i4::MIN.count_ones(), 1);
+        assert_eq!(i4::MIN.count_zeros(), 3);
+        assert_eq!(i1::new(-1).count_zeros(), 0);
+        assert_eq!(i127::new(-1).count_ones(), 127);
+        assert_eq!(i127::MIN.count_zeros(), 126);
+        assert_eq!(u4(0b11000110).count_ones(), 2);
+        assert_eq!(i4(0b0111_1000).count_zeros(), 3);
+
+        for i in -8..8 {
+            let x = i4::new(i);
+            assert_eq!(x.count_ones() + x.count_zeros(), i4::BITS);
+        }
+        for i in -1024..1024 {
+            let x = i11::new(i);
+            assert_eq!(x.count_ones() + x.count_zeros(), i11::BITS);
+            assert_eq!(x.count_ones(), (i as u16 & 0x7ff).count_ones());
+        }
+        for i in [i63::MIN, i63::new(-1), i63::new(-12345), i63::MAX] {
+            assert_eq!(i.count_ones() + i.count_zeros(), i63::BITS);
+        }
     }
 
     #[test]
-    fn test_bitand() {
-        assert_eq!(i9(-7) & i9(-9), i9::from(-7i8 & -9i8));
-        assert_eq!(&i9(-7) & i9(-9), i9::from(&-7i8 & -9i8));
-        assert_eq!(i9(-7) & &i9(-9), i9::from(-7i8 & &-9i8));
-        assert_eq!(&i9(-7) & &i9(-9), i9::from(&-7i8 & &-9i8));
+    fn test_from_bits() {
+        assert_eq!(u4::from_bits(0b1111_0110), u4::new(0b0110));
+        assert_eq!(i4::from_bits(0b0000_1111), i4::new(-1));
+        assert_eq!(i4::from_bits(0b1111_0111u8 as i8), i4::new(7));
+
+        // Clean input.
+        assert_eq!(u4::checked_from_bits(0b0110), Ok(u4::new(0b0110)));
+        assert_eq!(i4::checked_from_bits(7), Ok(i4::new(7)));
+        // Correctly sign extended negative input.
+        assert_eq!(i4::checked_from_bits(0b1111_1001u8 as i8), Ok(i4::new(-7)));
+        assert_eq!(i127::checked_from_bits(-1), Ok(i127::new(-1)));
+        // Zero extended input with the sign bit set.
+        let error = i4::checked_from_bits(0b0000_1001).unwrap_err();
+        assert_eq!((error.value(), error.target()), (Some(9), Some("i4")));
+        assert!(i127::checked_from_bits(1 << 126).is_err());
+        // Sign extended input with the sign bit clear.
+        assert!(i4::checked_from_bits(0b1111_0111u8 as i8).is_err());
+        // Unsigned input with bits above the width.
+        assert_eq!(
+            u4::checked_from_bits(0b1_0110).unwrap_err().value(),
+            Some(0b1_0110)
+        );
+        assert_eq!(
+            u127::checked_from_bits(u128::MAX).unwrap_err().value(),
+            Some(i128::MAX)
+        );
+    }
 
-        assert_eq!(u9(8) & u9(9), u9(8));
-        assert_eq!(&u9(8) & u9(9), u9(8));
-        assert_eq!(u9(8) & &u9(9), u9(8));
-        assert_eq!(&u9(8) & &u9(9), u9(8));
+    #[test]
+    fn test_try_from_bytes() {
+        assert_eq!(u12::try_from_be_bytes(&[0x0a, 0xbc]), Ok(u12::new(0xabc)));
+        assert_eq!(u12::try_from_le_bytes(&[0xbc, 0x0a]), Ok(u12::new(0xabc)));
+        assert_eq!(u12::try_from_be_bytes(&[0x0f, 0xff]), Ok(u12::MAX));
+        assert_eq!(
+            u12::try_from_be_bytes(&[0x1a, 0xbc]).unwrap_err().value(),
+            Some(0x1abc)
+        );
+        assert!(u12::try_from_le_bytes(&[0xbc, 0x8a]).is_err());
+
+        assert_eq!(u1::try_from_be_bytes(&[1]), Ok(u1::new(1)));
+        assert!(u1::try_from_be_bytes(&[2]).is_err());
+        assert_eq!(
+            u127::try_from_be_bytes(&[0x7f; 16]).map(|x| x.0),
+            Ok(0x7f7f_7f7f_7f7f_7f7f_7f7f_7f7f_7f7f_7f7f)
+        );
+        assert!(u127::try_from_be_bytes(&[0xff; 16]).is_err());
+
+        assert_eq!(i12::try_from_be_bytes(&[0xf8, 0x00]), Ok(i12::MIN));
+        assert_eq!(i12::try_from_be_bytes(&[0xff, 0xff]), Ok(i12::new(-1)));
+        assert_eq!(i12::try_from_be_bytes(&[0x07, 0xff]), Ok(i12::MAX));
+        assert_eq!(
+            i12::try_from_be_bytes(&[0x08, 0x00]).unwrap_err().value(),
+            Some(0x800)
+        );
+        assert_eq!(
+            i12::try_from_le_bytes(&[0xff, 0xf7]).unwrap_err().value(),
+            Some(-0x801)
+        );
+        assert_eq!(i127::try_from_le_bytes(&[0xff; 16]), Ok(i127::new(-1)));
     }
 
     #[test]
-    fn test_bitand_assign() {
-        let mut x = u12(255);
-        x &= u12(127);
-        assert_eq!(x, u12(127));
-        x &= u12(7);
-        assert_eq!(x, u12(7));
-        x &= u12(127);
-        assert_eq!(x, u12(7));
-        x &= u12(4);
-        assert_eq!(x, u12(4));
+    #[should_panic]
+    fn test_try_from_bytes_wrong_length() {
+        let _ = u12::try_from_be_bytes(&[0x0a, 0xbc, 0x00]);
+    }
+
+    fn hash_of<T: Hash>(value: T) -> u64 {
+        struct Fnv(u64);
+
+        impl Hasher for Fnv {
+            fn finish(&self) -> u64 {
+                self.0
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                for byte in bytes {
+                    self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3);
+                }
+            }
+        }
+
+        let mut hasher = Fnv(0xcbf2_9ce4_8422_2325);
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    macro_rules! test_hash_consistency {
+        ($($name:ident: $type:ident),*) => {$(
+            // A value and the same value with every bit above the width flipped.
+            let clean = $name::MAX.wrapping_sub($name(3)).mask().0;
+            let dirty = clean ^ !$name::MASK;
+            assert_eq!($name(clean), $name(dirty));
+            assert_eq!(hash_of($name(clean)), hash_of($name(dirty)));
+
+            let clean = $name::MIN.wrapping_add($name(3)).mask().0;
+            let dirty = clean ^ !$name::MASK;
+            assert_eq!($name(clean), $name(dirty));
+            assert_eq!(hash_of($name(clean)), hash_of($name(dirty)));
+        )*};
+    }
+
+    #[test]
+    fn test_hash() {
+        test_hash_consistency!(
+            u3: u8, u7: u8, u9: u16, u15: u16, u17: u32, u31: u32, u33: u64, u63: u64,
+            u65: u128, u127: u128
+        );
+        test_hash_consistency!(
+            i3: i8, i7: i8, i9: i16, i15: i16, i17: i32, i31: i32, i33: i64, i63: i64,
+            i65: i128, i127: i128
+        );
+
+        assert_eq!(hash_of(i4(0b0000_1110)), hash_of(i4(-2)));
+        assert_eq!(hash_of(i4(0b0111_0110)), hash_of(i4(6)));
+        assert_ne!(hash_of(i4(-2)), hash_of(i4(6)));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_hash_map() {
+        use std::collections::HashMap;
+
+        let mut map = HashMap::new();
+        map.insert(i4(-2), "minus two");
+        map.insert(i4(6), "six");
+        map.insert(i4::MIN, "min");
+
+        assert_eq!(map.get(&i4(0b0000_1110)), Some(&"minus two"));
+        assert_eq!(map.get(&i4(0b0111_0110)), Some(&"six"));
+        assert_eq!(map.get(&i4(0b0101_1000)), Some(&"min"));
+        assert_eq!(map.get(&i4(0b0101_0111)), None);
+
+        let mut map = HashMap::new();
+        map.insert(u39(0x7f_ffff_ffff), 1);
+        *map.get_mut(&u39(0xff_ffff_ffff)).unwrap() += 10;
+        assert_eq!(map[&u39::MAX], 11);
+
+        let mut map = HashMap::new();
+        map.insert(i39(-0x40_0000_0000), 2);
+        *map.get_mut(&i39(0x40_0000_0000)).unwrap() += 10;
+        assert_eq!(map[&i39::MIN], 12);
     }
 
     #[test]
@@ -970,4 +5542,21 @@ mod tests {
         assert_eq!(!u7(0), u7(0x7F));
         assert_eq!(!u7(56), u7(71));
     }
+
+    #[test]
+    fn test_not_masks_result() {
+        assert_eq!((!u4::new(0b0101)).0, 0b1010);
+        assert_eq!((!&u4::new(0b0101)).0, 0b1010);
+        assert_eq!((!u4(0b1111_0000)).0, 0b1111);
+        assert_eq!((!u127::MAX).0, 0);
+
+        for value in i12::MIN.0..=i12::MAX.0 {
+            let x = i12::new(value);
+            // !x == -x - 1, computed with wrapping operations as -MIN does not fit.
+            assert_eq!(!x, i12::new(0).wrapping_sub(x).wrapping_sub(i12::new(1)));
+            assert_eq!((!x).0, !value);
+        }
+        assert_eq!(!i1::new(0), i1::new(-1));
+        assert_eq!(!i127::MIN, i127::MAX);
+    }
 }
@@ -0,0 +1,196 @@
+use crate::*;
+
+/// The error type returned when parsing an integer from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIntError {
+    kind: IntErrorKind,
+}
+
+/// The reason parsing an integer failed, as returned by [`ParseIntError::kind`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IntErrorKind {
+    /// The string to parse is empty.
+    Empty,
+    /// The string contains a character that is not a digit in the given radix, or an
+    /// underscore that does not separate two digits.
+    InvalidDigit,
+    /// The value is larger than the largest value of the target type.
+    PosOverflow,
+    /// The value is smaller than the smallest value of the target type.
+    NegOverflow,
+}
+
+impl ParseIntError {
+    pub(crate) fn new(kind: IntErrorKind) -> Self {
+        ParseIntError { kind }
+    }
+
+    /// Returns the reason parsing the integer failed.
+    pub fn kind(&self) -> &IntErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for ParseIntError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self.kind {
+            IntErrorKind::Empty => "cannot parse integer from empty string",
+            IntErrorKind::InvalidDigit => "invalid digit found in string",
+            IntErrorKind::PosOverflow => "number too large to fit in target type",
+            IntErrorKind::NegOverflow => "number too small to fit in target type",
+        })
+    }
+}
+
+impl core::error::Error for ParseIntError {}
+
+/// Parses `src` in the given radix into an `i128` in the range `min..=max`.
+///
+/// The digits may be separated by single underscores, like in Rust literals. A leading
+/// `-` is only accepted if `min` is negative.
+pub(crate) fn parse_i128(
+    src: &str,
+    radix: u32,
+    min: i128,
+    max: i128,
+) -> Result<i128, ParseIntError> {
+    assert!(
+        (2..=36).contains(&radix),
+        "radix must be in the range 2..=36"
+    );
+
+    let (negative, digits) = match src.as_bytes() {
+        [] => return Err(ParseIntError::new(IntErrorKind::Empty)),
+        [b'+' | b'-'] => return Err(ParseIntError::new(IntErrorKind::InvalidDigit)),
+        [b'+', rest @ ..] => (false, rest),
+        [b'-', rest @ ..] if min < 0 => (true, rest),
+        digits => (false, digits),
+    };
+
+    let overflow = if negative {
+        IntErrorKind::NegOverflow
+    } else {
+        IntErrorKind::PosOverflow
+    };
+    let mut value: i128 = 0;
+    let mut previous_is_digit = false;
+    for &c in digits {
+        if c == b'_' {
+            if !previous_is_digit {
+                return Err(ParseIntError::new(IntErrorKind::InvalidDigit));
+            }
+            previous_is_digit = false;
+            continue;
+        }
+        let digit = (c as char)
+            .to_digit(radix)
+            .ok_or(ParseIntError::new(IntErrorKind::InvalidDigit))?;
+        // Negative values are accumulated downwards so that the most negative value of
+        // the backing type can be represented.
+        value = value
+            .checked_mul(radix.into())
+            .and_then(|value| {
+                if negative {
+                    value.checked_sub(digit.into())
+                } else {
+                    value.checked_add(digit.into())
+                }
+            })
+            .ok_or(ParseIntError::new(overflow))?;
+        previous_is_digit = true;
+    }
+    if !previous_is_digit {
+        return Err(ParseIntError::new(IntErrorKind::InvalidDigit));
+    }
+
+    if value > max {
+        Err(ParseIntError::new(IntErrorKind::PosOverflow))
+    } else if value < min {
+        Err(ParseIntError::new(IntErrorKind::NegOverflow))
+    } else {
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("17".parse::<u5>(), Ok(u5::new(17)));
+        assert_eq!("+31".parse::<u5>(), Ok(u5::MAX));
+        assert_eq!("-16".parse::<i5>(), Ok(i5::MIN));
+        assert_eq!("0".parse::<i1>(), Ok(i1::new(0)));
+        assert_eq!(
+            "170141183460469231731687303715884105727".parse::<u127>(),
+            Ok(u127::MAX)
+        );
+        assert_eq!(
+            "-85070591730234615865843651857942052864".parse::<i127>(),
+            Ok(i127::MIN)
+        );
+    }
+
+    #[test]
+    fn test_from_str_radix() {
+        assert_eq!(u5::from_str_radix("10110", 2), Ok(u5::new(0b10110)));
+        assert_eq!(u12::from_str_radix("fFf", 16), Ok(u12::MAX));
+        assert_eq!(i7::from_str_radix("-z", 36), Ok(i7::new(-35)));
+        assert_eq!(u7::from_str_radix("10201", 3), Ok(u7::new(100)));
+    }
+
+    #[test]
+    fn test_from_str_underscores() {
+        assert_eq!("1_000".parse::<u10>(), Ok(u10::new(1000)));
+        assert_eq!("-1_0_0".parse::<i9>(), Ok(i9::new(-100)));
+        assert_eq!(u12::from_str_radix("f_ff", 16), Ok(u12::MAX));
+        assert_eq!(u5::from_str_radix("1_0110", 2), Ok(u5::new(0b10110)));
+
+        for src in ["_1000", "1000_", "1__000", "_", "-_1", "+_1", "1_000_"] {
+            let kind = *src.parse::<i12>().unwrap_err().kind();
+            assert_eq!(kind, IntErrorKind::InvalidDigit, "{src:?}");
+        }
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        let kind = |r: Result<u5, ParseIntError>| *r.unwrap_err().kind();
+        assert_eq!(kind("".parse()), IntErrorKind::Empty);
+        assert_eq!(kind("+".parse()), IntErrorKind::InvalidDigit);
+        assert_eq!(kind("-".parse()), IntErrorKind::InvalidDigit);
+        assert_eq!(kind("-1".parse()), IntErrorKind::InvalidDigit);
+        assert_eq!(kind("-0".parse()), IntErrorKind::InvalidDigit);
+        assert_eq!(kind("1a".parse()), IntErrorKind::InvalidDigit);
+        assert_eq!(kind(" 1".parse()), IntErrorKind::InvalidDigit);
+        assert_eq!(kind("32".parse()), IntErrorKind::PosOverflow);
+        assert_eq!(
+            kind("1_000_000_000_000_000_000_000_000_000_000_000_000_000_000".parse()),
+            IntErrorKind::PosOverflow
+        );
+        assert_eq!(kind(u5::from_str_radix("2", 2)), IntErrorKind::InvalidDigit);
+
+        assert_eq!(
+            *"-17".parse::<i5>().unwrap_err().kind(),
+            IntErrorKind::NegOverflow
+        );
+        assert_eq!(
+            *"16".parse::<i5>().unwrap_err().kind(),
+            IntErrorKind::PosOverflow
+        );
+        assert_eq!(
+            *"-1_000_000_000_000_000_000_000_000_000_000_000_000_000_000"
+                .parse::<i127>()
+                .unwrap_err()
+                .kind(),
+            IntErrorKind::NegOverflow
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "radix must be in the range 2..=36")]
+    fn test_from_str_radix_invalid_radix() {
+        let _ = u5::from_str_radix("1", 37);
+    }
+}
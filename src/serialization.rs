@@ -0,0 +1,136 @@
+//! Serialization of the uX types with `serde`.
+//!
+//! Every type is serialized as its backing primitive, so `u63` and the other types
+//! stored in a `u64` use `serialize_u64`, and `u127` uses `serialize_u128`. Formats
+//! like JSON thereby write them as plain integers, without going through a float or a
+//! string, and nothing is lost. Deserialization accepts the backing primitive and
+//! fails if the value is out of range for the type.
+
+use crate::*;
+
+use ::serde::de::{Deserialize, Deserializer, Error};
+use ::serde::ser::{Serialize, Serializer};
+
+macro_rules! implement_serde {
+    ($type:ident, $serialize:ident: $($name:ident),*) => {$(
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.$serialize(self.mask().0)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = $type::deserialize(deserializer)?;
+                if ($name::MIN.0..=$name::MAX.0).contains(&value) {
+                    Ok($name(value))
+                } else {
+                    Err(D::Error::custom(format_args!(
+                        "{} out of range for {} ({}..={})",
+                        value,
+                        stringify!($name),
+                        $name::MIN,
+                        $name::MAX
+                    )))
+                }
+            }
+        }
+    )*};
+}
+
+implement_serde!(u8, serialize_u8:
+    u1, u2, u3, u4, u5, u6, u7
+);
+
+implement_serde!(u16, serialize_u16:
+    u9, u10, u11, u12, u13, u14, u15
+);
+
+implement_serde!(u32, serialize_u32:
+    u17, u18, u19, u20, u21, u22, u23, u24, u25, u26, u27, u28, u29, u30, u31
+);
+
+implement_serde!(u64, serialize_u64:
+    u33, u34, u35, u36, u37, u38, u39, u40, u41, u42, u43, u44, u45, u46, u47, u48, u49, u50,
+    u51, u52, u53, u54, u55, u56, u57, u58, u59, u60, u61, u62, u63
+);
+
+implement_serde!(u128, serialize_u128:
+    u65, u66, u67, u68, u69, u70, u71, u72, u73, u74, u75, u76, u77, u78, u79, u80, u81, u82,
+    u83, u84, u85, u86, u87, u88, u89, u90, u91, u92, u93, u94, u95, u96, u97, u98, u99, u100,
+    u101, u102, u103, u104, u105, u106, u107, u108, u109, u110, u111, u112, u113, u114, u115,
+    u116, u117, u118, u119, u120, u121, u122, u123, u124, u125, u126, u127
+);
+
+implement_serde!(i8, serialize_i8:
+    i1, i2, i3, i4, i5, i6, i7
+);
+
+implement_serde!(i16, serialize_i16:
+    i9, i10, i11, i12, i13, i14, i15
+);
+
+implement_serde!(i32, serialize_i32:
+    i17, i18, i19, i20, i21, i22, i23, i24, i25, i26, i27, i28, i29, i30, i31
+);
+
+implement_serde!(i64, serialize_i64:
+    i33, i34, i35, i36, i37, i38, i39, i40, i41, i42, i43, i44, i45, i46, i47, i48, i49, i50,
+    i51, i52, i53, i54, i55, i56, i57, i58, i59, i60, i61, i62, i63
+);
+
+implement_serde!(i128, serialize_i128:
+    i65, i66, i67, i68, i69, i70, i71, i72, i73, i74, i75, i76, i77, i78, i79, i80, i81, i82,
+    i83, i84, i85, i86, i87, i88, i89, i90, i91, i92, i93, i94, i95, i96, i97, i98, i99, i100,
+    i101, i102, i103, i104, i105, i106, i107, i108, i109, i110, i111, i112, i113, i114, i115,
+    i116, i117, i118, i119, i120, i121, i122, i123, i124, i125, i126, i127
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json_u63_max() {
+        let json = serde_json::to_string(&u63::MAX).unwrap();
+        assert_eq!(json, "9223372036854775807");
+        assert_eq!(serde_json::from_str::<u63>(&json).unwrap(), u63::MAX);
+
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value.as_u64(), Some(u63::MAX.into()));
+    }
+
+    #[test]
+    fn test_serde_json_round_trip() {
+        for x in [u5::MIN, u5::new(17), u5::MAX] {
+            let json = serde_json::to_string(&x).unwrap();
+            assert_eq!(serde_json::from_str::<u5>(&json).unwrap(), x);
+        }
+
+        assert_eq!(serde_json::to_string(&i40::new(-1234)).unwrap(), "-1234");
+        assert_eq!(
+            serde_json::from_str::<i40>("-1234").unwrap(),
+            i40::new(-1234)
+        );
+        assert_eq!(
+            serde_json::to_string(&i63::MIN).unwrap(),
+            "-4611686018427387904"
+        );
+
+        let json = serde_json::to_string(&u127::MAX).unwrap();
+        assert_eq!(json, "170141183460469231731687303715884105727");
+        assert_eq!(serde_json::from_str::<u127>(&json).unwrap(), u127::MAX);
+    }
+
+    #[test]
+    fn test_serde_json_out_of_range() {
+        assert!(serde_json::from_str::<u5>("32").is_err());
+        #[cfg(feature = "std")]
+        assert!(serde_json::from_str::<u5>("32")
+            .unwrap_err()
+            .to_string()
+            .starts_with("32 out of range for u5 (0..=31)"));
+        assert!(serde_json::from_str::<i5>("-17").is_err());
+        assert!(serde_json::from_str::<u63>("9223372036854775808").is_err());
+    }
+}